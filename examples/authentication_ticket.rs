@@ -0,0 +1,37 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let ticket = client.authentication_ticket().await?;
+
+    println!("Generated authentication ticket.");
+
+    // Redeeming the ticket creates a brand new session.
+    let new_roblosecurity = ClientBuilder::new()
+        .build()
+        .redeem_authentication_ticket(ticket)
+        .await?;
+
+    let new_client = ClientBuilder::new()
+        .roblosecurity(new_roblosecurity)
+        .build();
+
+    println!(
+        "Redeemed ticket into a new session for {}.",
+        new_client.username().await?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use clap::Parser;
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    user_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if client.is_blocked(args.user_id).await? {
+        client.unblock_user(args.user_id).await?;
+        println!("Unblocked {}", args.user_id);
+    } else {
+        client.block_user(args.user_id).await?;
+        println!("Blocked {}", args.user_id);
+    }
+
+    let (blocked_users, _) = client.blocked_users(Limit::Fifty, None).await?;
+
+    for user in blocked_users {
+        println!("Blocked: {} ({})", user.username, user.user_id);
+    }
+
+    Ok(())
+}
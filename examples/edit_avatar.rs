@@ -0,0 +1,41 @@
+use clap::Parser;
+use roboat::avatar::AvatarType;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    asset_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let user_id = client.user_id().await?;
+    let avatar = client.avatar(user_id).await?;
+
+    println!("Currently wearing {} assets.", avatar.assets.len());
+
+    client.wear_asset(args.asset_id).await?;
+    println!("Now wearing asset {}.", args.asset_id);
+
+    client.set_avatar_type(AvatarType::R15).await?;
+    client.redraw_avatar_thumbnail().await?;
+
+    // Put the original outfit back on.
+    let original_asset_ids = avatar.assets.iter().map(|asset| asset.asset_id).collect();
+    client.set_worn_assets(original_asset_ids).await?;
+    client.set_body_colors(avatar.body_colors).await?;
+    client.set_avatar_scales(avatar.scales).await?;
+    client.set_avatar_type(avatar.avatar_type).await?;
+
+    println!("Restored original avatar.");
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use roboat::thumbnails::{ThumbnailSize, ThumbnailType};
+use roboat::{ClientBuilder, Limit, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RoboatError> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let (badges, _) = client.user_badges(args.user_id, Limit::Ten, None).await?;
+
+    let badge_ids: Vec<u64> = badges.iter().map(|badge| badge.badge_id).collect();
+
+    let awarded_dates = client
+        .badge_awarded_dates(args.user_id, badge_ids.clone())
+        .await?;
+
+    let icon_urls = client
+        .thumbnail_url_bulk(badge_ids, ThumbnailSize::S150x150, ThumbnailType::BadgeIcon)
+        .await?;
+
+    for (badge, icon_url) in badges.iter().zip(icon_urls) {
+        let awarded_date = awarded_dates
+            .iter()
+            .find(|x| x.badge_id == badge.badge_id)
+            .map(|x| x.awarded_date.as_str())
+            .unwrap_or("Unknown");
+
+        println!(
+            "{} / Awarded: {} / Icon: {}",
+            badge.name, awarded_date, icon_url
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use clap::Parser;
+use roboat::{ClientBuilder, Limit, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RoboatError> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let mut cursor = None;
+    let mut total_rap = 0;
+
+    loop {
+        let (collectibles, next_cursor) = client
+            .collectibles(args.user_id, Limit::Hundred, cursor)
+            .await?;
+
+        for collectible in collectibles {
+            println!(
+                "{} / UAID: {} / Serial: {:?} / RAP: {}",
+                collectible.name,
+                collectible.uaid,
+                collectible.serial_number,
+                collectible.recent_average_price
+            );
+
+            total_rap += collectible.recent_average_price;
+        }
+
+        cursor = next_cursor;
+
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    println!("Total RAP: {}", total_rap);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short)]
+    shout: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let details = client.group_details(args.group_id).await?;
+
+    println!("Group: {} ({})", details.name, details.group_id);
+    println!("Owner: {:?}", details.owner.map(|owner| owner.username));
+    println!("Members: {}", details.member_count);
+    println!("Public Entry: {}", details.public_entry_allowed);
+    println!("Verified: {}", details.has_verified_badge);
+    println!("Description: {}", details.description);
+
+    if let Some(shout) = details.shout {
+        println!("Current Shout: {}", shout.body);
+    }
+
+    if let Some(message) = args.shout {
+        client.set_group_shout(args.group_id, message).await?;
+        println!("Posted a new shout.");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use clap::Parser;
+use roboat::catalog::{AssetType, ItemType};
+use roboat::{ClientBuilder, InventoryError, Limit, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: u64,
+    #[arg(long, short)]
+    asset_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RoboatError> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    match client
+        .owns_item(args.user_id, ItemType::Asset, args.asset_id)
+        .await
+    {
+        Ok(owns_item) => println!("Owns asset {}: {}", args.asset_id, owns_item),
+        Err(RoboatError::InventoryError(InventoryError::InventoryPrivate)) => {
+            println!("User {} has a private inventory.", args.user_id);
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    }
+
+    let asset_types = vec![AssetType::Hat, AssetType::Shirt, AssetType::Pants];
+
+    let (items, _) = client
+        .inventory(args.user_id, asset_types, Limit::Hundred, None)
+        .await?;
+
+    for item in items {
+        println!(
+            "{} / ID: {} / Type: {:?}",
+            item.name, item.asset_id, item.asset_type
+        );
+    }
+
+    Ok(())
+}
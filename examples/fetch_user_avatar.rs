@@ -0,0 +1,33 @@
+use clap::Parser;
+use roboat::{ClientBuilder, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RoboatError> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let avatar = client.avatar(args.user_id).await?;
+
+    println!("Avatar Type: {:?}", avatar.avatar_type);
+    println!("Body Colors: {:?}", avatar.body_colors);
+    println!("Scales: {:?}", avatar.scales);
+
+    for asset in avatar.assets {
+        println!(
+            "Asset: {} / ID: {} / Type: {:?}",
+            asset.name, asset.asset_id, asset.asset_type
+        );
+    }
+
+    for emote in avatar.emotes {
+        println!("Emote Slot {}: {}", emote.position, emote.name);
+    }
+
+    Ok(())
+}
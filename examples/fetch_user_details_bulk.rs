@@ -0,0 +1,21 @@
+use roboat::ClientBuilder;
+
+const USER_IDS: [u64; 3] = [2207291, 156, 1];
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = ClientBuilder::new().build();
+
+    let (users, missing_user_ids) = client.user_details_bulk(USER_IDS.to_vec()).await?;
+
+    for user in users {
+        println!(
+            "User ID: {} / Username: {} / Display Name: {}",
+            user.user_id, user.username, user.display_name
+        );
+    }
+
+    println!("Missing User IDs: {:?}", missing_user_ids);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let user_groups = client.user_groups(args.user_id).await?;
+
+    println!("User is in {} groups.", user_groups.len());
+    for user_group in user_groups {
+        println!(
+            "{} ({}) / Role: {} / Rank: {}",
+            user_group.group.name,
+            user_group.group.group_id,
+            user_group.role.name,
+            user_group.role.rank
+        );
+    }
+
+    match client.primary_group(args.user_id).await? {
+        Some(user_group) => println!("Primary Group: {}", user_group.group.name),
+        None => println!("User has no primary group."),
+    }
+
+    Ok(())
+}
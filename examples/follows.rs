@@ -0,0 +1,40 @@
+use clap::Parser;
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    target_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let follower_count = client.follower_count(args.target_id).await?;
+    let following_count = client.following_count(args.target_id).await?;
+
+    println!(
+        "User {} has {} followers and is following {} users.",
+        args.target_id, follower_count, following_count
+    );
+
+    let (followers, _) = client.followers(args.target_id, Limit::Ten, None).await?;
+
+    for follower in followers {
+        println!("Follower: {}", follower.user_id);
+    }
+
+    client.follow(args.target_id).await?;
+    println!("Followed {}", args.target_id);
+
+    client.unfollow(args.target_id).await?;
+    println!("Unfollowed {}", args.target_id);
+
+    Ok(())
+}
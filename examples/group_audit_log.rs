@@ -0,0 +1,60 @@
+use clap::Parser;
+use roboat::groups::{AuditLogAction, AuditLogActionType};
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short)]
+    user_id: Option<u64>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let mut cursor = None;
+
+    // Print every rank change, following the cursor until there are no more pages.
+    loop {
+        let (entries, next_cursor) = client
+            .group_audit_log(
+                args.group_id,
+                Some(AuditLogActionType::ChangeRank),
+                args.user_id,
+                Limit::Hundred,
+                cursor,
+            )
+            .await?;
+
+        for entry in entries {
+            if let AuditLogAction::ChangeRank {
+                target_name,
+                old_role_name,
+                new_role_name,
+                ..
+            } = entry.action
+            {
+                let actor = entry.actor.map(|actor| actor.username).unwrap_or_default();
+
+                println!(
+                    "[{}] {} changed {} from {} to {}",
+                    entry.created, actor, target_name, old_role_name, new_role_name
+                );
+            }
+        }
+
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use clap::Parser;
+use roboat::{ClientBuilder, GroupError, Limit, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let join_requests = match client
+        .group_join_requests(args.group_id, Limit::Hundred, None)
+        .await
+    {
+        Ok((join_requests, _)) => join_requests,
+        Err(RoboatError::GroupError(GroupError::InsufficientPermissions)) => {
+            println!("You cannot manage join requests in this group.");
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut to_accept = Vec::new();
+    let mut to_decline = Vec::new();
+
+    // Accept users with a display name, decline everyone else.
+    for join_request in join_requests {
+        let requester = join_request.requester;
+
+        if requester.display_name != requester.username {
+            to_accept.push(requester.user_id);
+        } else {
+            to_decline.push(requester.user_id);
+        }
+    }
+
+    println!("Accepting {:?}", to_accept);
+    client
+        .accept_group_join_requests(args.group_id, to_accept)
+        .await?;
+
+    println!("Declining {:?}", to_decline);
+    client
+        .decline_group_join_requests(args.group_id, to_decline)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use clap::Parser;
+use roboat::groups::PayoutArgs;
+use roboat::{ClientBuilder, PayoutError, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short)]
+    user_id: u64,
+    #[arg(long, short)]
+    amount: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let funds = client.group_funds(args.group_id).await?;
+    println!("Group Funds: {}", funds);
+
+    let recipients = client.group_payout_recipients(args.group_id).await?;
+    for recipient in recipients {
+        println!(
+            "Recurring: {} receives {}%",
+            recipient.user.username, recipient.percentage
+        );
+    }
+
+    let payout = PayoutArgs {
+        user_id: args.user_id,
+        amount: args.amount,
+    };
+
+    match client.group_payout(args.group_id, vec![payout]).await {
+        Ok(_) => println!("Paid out {} robux to {}.", args.amount, args.user_id),
+        Err(RoboatError::PayoutError(PayoutError::InsufficientFunds)) => {
+            println!("The group only has {} robux.", funds)
+        }
+        Err(RoboatError::PayoutError(PayoutError::RecipientNotEligible)) => {
+            println!("{} cannot receive payouts yet.", args.user_id)
+        }
+        Err(RoboatError::PayoutError(PayoutError::ChallengeRequired(challenge_id))) => {
+            println!("Complete challenge {} and try again.", challenge_id)
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    /// The group whose roles and permissions are copied.
+    #[arg(long, short)]
+    template_group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let existing_ranks: Vec<u8> = client
+        .group_roles(args.group_id)
+        .await?
+        .into_iter()
+        .map(|role| role.rank)
+        .collect();
+
+    for template_role in client.group_roles(args.template_group_id).await? {
+        // The guest and owner roles always exist, and other ranks may already be taken.
+        if existing_ranks.contains(&template_role.rank) {
+            continue;
+        }
+
+        let permissions = client
+            .group_role_permissions(args.template_group_id, template_role.id)
+            .await?;
+
+        let role = client
+            .create_group_role(
+                args.group_id,
+                template_role.name,
+                String::new(),
+                template_role.rank,
+                false,
+            )
+            .await?;
+
+        client
+            .set_group_role_permissions(args.group_id, role.id, permissions)
+            .await?;
+
+        println!("Created role {} (rank {}).", role.name, role.rank);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use clap::Parser;
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    /// Every post containing this phrase is treated as spam, and all posts by its poster are deleted.
+    #[arg(long, short)]
+    spam_phrase: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let (posts, _) = client
+        .group_wall_posts(args.group_id, Limit::Hundred, None)
+        .await?;
+
+    let mut spammers = Vec::new();
+
+    for post in posts {
+        if !post.body.contains(&args.spam_phrase) {
+            continue;
+        }
+
+        match post.poster {
+            Some(poster) if !spammers.contains(&poster.user_id) => {
+                spammers.push(poster.user_id);
+            }
+            Some(_) => {}
+            None => {
+                client
+                    .delete_group_wall_post(args.group_id, post.post_id)
+                    .await?;
+                println!("Deleted post {}.", post.post_id);
+            }
+        }
+    }
+
+    for user_id in spammers {
+        client
+            .delete_group_wall_posts_by_user(args.group_id, user_id)
+            .await?;
+        println!("Deleted all posts by {}.", user_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use roboat::{ClientBuilder, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    username: String,
+    #[arg(long, short)]
+    password: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let result = ClientBuilder::new()
+        .login(args.username, args.password)
+        .await;
+
+    let client = match result {
+        Ok(client) => client,
+        Err(RoboatError::TwoStepVerificationRequired(challenge)) => {
+            println!("Enter the code sent using {:?}:", challenge.media_type);
+
+            let mut code = String::new();
+            std::io::stdin().read_line(&mut code)?;
+
+            ClientBuilder::new()
+                .login_two_step(challenge, code.trim().to_string())
+                .await?
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    println!(
+        "Logged in as {} ({}).",
+        client.username().await?,
+        client.user_id().await?
+    );
+
+    Ok(())
+}
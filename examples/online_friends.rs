@@ -0,0 +1,37 @@
+use clap::Parser;
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let user_id = client.user_id().await?;
+    let friend_count = client.friend_count(user_id).await?;
+
+    println!("You have {} friends.", friend_count);
+
+    let (friend_ids, _) = client.friends_page(user_id, Limit::Fifty, None).await?;
+    println!("First page of friends: {:?}", friend_ids);
+
+    let online_friends = client.online_friends().await?;
+
+    println!("{} friends are online.", online_friends.len());
+
+    for friend in online_friends {
+        println!(
+            "{} / {:?} / Location: {:?} / Place: {:?}",
+            friend.user.username, friend.presence_type, friend.last_location, friend.place_id
+        );
+    }
+
+    Ok(())
+}
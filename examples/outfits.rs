@@ -0,0 +1,38 @@
+use clap::Parser;
+use roboat::thumbnails::{ThumbnailSize, ThumbnailType};
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let (outfits, _) = client.outfits(args.user_id, None, Limit::Ten, None).await?;
+
+    for outfit in outfits {
+        let details = client.outfit_details(outfit.outfit_id).await?;
+        let thumbnail_url = client
+            .thumbnail_url(
+                outfit.outfit_id,
+                ThumbnailSize::S420x420,
+                ThumbnailType::Outfit,
+            )
+            .await?;
+
+        println!(
+            "Outfit: {} / Assets: {} / Type: {:?} / Thumbnail: {}",
+            details.name,
+            details.assets.len(),
+            details.avatar_type,
+            thumbnail_url
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use std::num::NonZeroUsize;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// Declines every friend request instead of filtering them.
+    #[arg(long, short)]
+    decline_all: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if args.decline_all {
+        client.decline_all_friend_requests().await?;
+        println!("Declined all friend requests.");
+        return Ok(());
+    }
+
+    // Accept requests from users we share at least one friend with.
+    let concurrency = NonZeroUsize::new(5).unwrap();
+
+    let processed = client
+        .process_friend_requests(
+            |request| !request.mutual_friends_list.is_empty(),
+            concurrency,
+        )
+        .await?;
+
+    println!("Accepted: {:?}", processed.accepted);
+    println!("Declined: {:?}", processed.declined);
+
+    if let Some(e) = processed.error {
+        return Err(e.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short)]
+    user_id: u64,
+    /// The rank to set. The user is promoted by one role if this is not given.
+    #[arg(long)]
+    rank: Option<u8>,
+    /// Exiles the user from the group instead of ranking them.
+    #[arg(long, short)]
+    exile: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if args.exile {
+        client.exile_member(args.user_id, args.group_id).await?;
+        println!("Exiled user {} from group {}.", args.user_id, args.group_id);
+        return Ok(());
+    }
+
+    match args.rank {
+        Some(rank) => {
+            client
+                .set_group_member_rank(args.user_id, args.group_id, rank)
+                .await?;
+            println!("Set user {}'s rank to {}.", args.user_id, rank);
+        }
+        None => {
+            let new_role = client.promote(args.user_id, args.group_id).await?;
+            println!(
+                "Promoted user {} to {} (rank {}).",
+                args.user_id, new_role.name, new_role.rank
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use roboat::RoboatError;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RoboatError> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let status = client.session_status().await?;
+
+    if !status.is_valid {
+        println!("Roblosecurity is invalid.");
+        return Ok(());
+    }
+
+    println!("User ID: {}", status.user_id);
+    println!("Terminated: {:?}", status.is_terminated);
+    println!(
+        "Moderated: {:?} ({})",
+        status.is_moderated,
+        status.moderation_type.unwrap_or_default()
+    );
+    println!("Email Verified: {:?}", status.is_email_verified);
+    println!("Phone Verified: {:?}", status.is_phone_verified);
+    println!("Premium: {:?}", status.is_premium);
+    println!("Age Bracket: {:?}", status.age_bracket);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use roboat::RoboatError;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// Revokes every session except the current one.
+    #[arg(long, default_value_t = false)]
+    revoke_others: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RoboatError> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let mut next_cursor = None;
+
+    loop {
+        let (sessions, cursor) = client.sessions(next_cursor).await?;
+
+        for session in sessions {
+            println!(
+                "Device: {} {} / Location: {}, {} / Current: {}",
+                session.device_name.unwrap_or_default(),
+                session.os.unwrap_or_default(),
+                session.city.unwrap_or_default(),
+                session.country.unwrap_or_default(),
+                session.is_current_session
+            );
+        }
+
+        next_cursor = cursor;
+
+        if next_cursor.is_none() {
+            break;
+        }
+    }
+
+    if args.revoke_others {
+        let new_roblosecurity = client.revoke_other_sessions().await?;
+
+        println!(
+            "Revoked all other sessions. New roblosecurity: {}",
+            new_roblosecurity
+        );
+    }
+
+    Ok(())
+}
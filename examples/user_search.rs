@@ -1,5 +1,5 @@
 use clap::Parser;
-use roboat::ClientBuilder;
+use roboat::{ClientBuilder, Limit};
 
 #[derive(Parser, Debug)]
 struct Args {
@@ -16,12 +16,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let keyword = "linkmon".to_string();
 
-    let users = client.user_search(keyword).await?;
+    let users = client.user_search(keyword.clone()).await?;
 
     println!("Found {} users.", users.len());
     for user in users {
         println!("{}: {}", user.username, user.user_id);
     }
 
+    let (results, _) = client.user_search_page(keyword, Limit::Ten, None).await?;
+
+    for result in results {
+        println!(
+            "{}: {} / Verified: {} / Previous Usernames: {:?}",
+            result.username, result.user_id, result.has_verified_badge, result.previous_usernames
+        );
+    }
+
     Ok(())
 }
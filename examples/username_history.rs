@@ -0,0 +1,34 @@
+use clap::Parser;
+use roboat::{ClientBuilder, Limit, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RoboatError> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let mut cursor = None;
+
+    loop {
+        let (usernames, next_cursor) = client
+            .username_history(args.user_id, Limit::Hundred, cursor)
+            .await?;
+
+        for username in usernames {
+            println!("Previous Username: {}", username);
+        }
+
+        cursor = next_cursor;
+
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    Ok(())
+}
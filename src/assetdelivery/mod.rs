@@ -132,7 +132,7 @@ impl Client {
     ///
     /// ```no_run
     /// use clap::Parser;
-    /// use roboat::assetdelivery::request_types::AssetBatchPayload;
+    /// use roboat::assetdelivery::AssetBatchPayload;
     ///
     /// #[derive(Parser, Debug)]
     /// struct Args {
@@ -215,7 +215,7 @@ impl Client {
     /// # Ok(())
     /// # }
     /// ```
-
+    //
     // WARNING: Theres a V2 API https://assetdelivery.roblox.com/v2/assetid/119472671657225 that
     // has location of the file. Migrate to it if they ever ratelimit/remove the v1 API
     /// If this API hangs, use a timeout and retry.
@@ -272,9 +272,8 @@ mod internal {
             // CSRF on this API)
             for batch_resp in &mut meta_data {
                 if let Some(id) = batch_resp.asset_type_id {
-                    match catalog_types::AssetType::try_from(id as u64) {
-                        Ok(e) => batch_resp.asset_type = Some(e),
-                        Err(..) => {}
+                    if let Ok(e) = catalog_types::AssetType::try_from(id as u64) {
+                        batch_resp.asset_type = Some(e)
                     }
                 }
                 if let Some(roblox_error_raw) = &batch_resp.errors {
@@ -1,8 +1,80 @@
-use crate::{Client, RoboatError, XCSRF_HEADER};
-use reqwest::header;
+use crate::users::ClientUserInformation;
+use crate::{ChallengeInfo, ChallengeType, Client, ClientBuilder, RoboatError, XCSRF_HEADER};
+use reqwest::header::{self, HeaderMap};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
 
 const AUTH_BASE_URL: &str = "https://auth.roblox.com/";
 
+const AUTHENTICATION_TICKET_API: &str = "https://auth.roblox.com/v1/authentication-ticket";
+const REDEEM_AUTHENTICATION_TICKET_API: &str =
+    "https://auth.roblox.com/v1/authentication-ticket/redeem";
+
+/// The header the authentication ticket is returned in.
+const AUTHENTICATION_TICKET_HEADER: &str = "rbx-authentication-ticket";
+/// Roblox refuses to issue or redeem tickets unless this header is present.
+const AUTHENTICATION_NEGOTIATION_HEADER: &str = "RBXAuthenticationNegotiation";
+const AUTHENTICATION_NEGOTIATION_VALUE: &str = "1";
+
+const LOGOUT_FROM_ALL_SESSIONS_API: &str =
+    "https://auth.roblox.com/v2/logoutfromallsessionsandreauthenticate";
+
+const LOGIN_API: &str = "https://auth.roblox.com/v2/login";
+const VERIFY_TWO_STEP_CODE_API: &str =
+    "https://twostepverification.roblox.com/v1/users/{user_id}/challenges/{media_type}/verify";
+const TWO_STEP_LOGIN_API: &str =
+    "https://auth.roblox.com/v3/users/{user_id}/two-step-verification/login";
+
+const LOGIN_CREDENTIAL_TYPE: &str = "Username";
+const TWO_STEP_ACTION_TYPE: &str = "Login";
+
+const CHALLENGE_ID_HEADER: &str = "rblx-challenge-id";
+const CHALLENGE_TYPE_HEADER: &str = "rblx-challenge-type";
+const CHALLENGE_METADATA_HEADER: &str = "rblx-challenge-metadata";
+
+const ROBLOSECURITY_COOKIE_PREFIX: &str = ".ROBLOSECURITY=";
+
+/// The method a two step verification code is delivered by.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum TwoStepVerificationMediaType {
+    #[default]
+    Email,
+    Authenticator,
+    #[serde(rename = "SMS")]
+    Sms,
+    SecurityKey,
+    RecoveryCode,
+}
+
+impl std::fmt::Display for TwoStepVerificationMediaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Email => write!(f, "email"),
+            Self::Authenticator => write!(f, "authenticator"),
+            Self::Sms => write!(f, "sms"),
+            Self::SecurityKey => write!(f, "security-key"),
+            Self::RecoveryCode => write!(f, "recovery-codes"),
+        }
+    }
+}
+
+/// The two step verification challenge returned when logging in to an account
+/// that has two step verification enabled. Used to resume the login with
+/// [`ClientBuilder::login_two_step`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct TwoStepVerificationChallenge {
+    /// The user id of the account being logged in to.
+    pub user_id: u64,
+    /// The id of the challenge (referred to as the "ticket" by Roblox).
+    pub challenge_id: String,
+    /// How the code was sent to the user.
+    pub media_type: TwoStepVerificationMediaType,
+}
+
 impl Client {
     /// Used to force refresh the xcsrf. This does not invalidate the current xcsrf, it just
     /// makes sure that the current xcsrf is valid and adds a new one if it is not.
@@ -59,4 +131,647 @@ impl Client {
             },
         }
     }
+
+    /// Generates an authentication ticket for the account of the roblosecurity using
+    /// <https://auth.roblox.com/v1/authentication-ticket>.
+    ///
+    /// An authentication ticket is a short-lived, single-use token that can be redeemed
+    /// for a new roblosecurity session (see [`Client::redeem_authentication_ticket`]),
+    /// or passed to the Roblox player to join a game.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Tickets expire shortly after being generated and can only be redeemed once.
+    ///
+    /// # Return Value Notes
+    /// * Returns the ticket taken from the `rbx-authentication-ticket` header.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::MalformedResponse`] - If the ticket header is not returned.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let ticket = client.authentication_ticket().await?;
+    /// println!("Authentication Ticket: {}", ticket);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn authentication_ticket(&self) -> Result<String, RoboatError> {
+        match self.authentication_ticket_internal().await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.authentication_ticket_internal().await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Redeems an authentication ticket for a new roblosecurity using
+    /// <https://auth.roblox.com/v1/authentication-ticket/redeem>.
+    ///
+    /// The returned roblosecurity belongs to a new session, separate from the
+    /// session that generated the ticket. It can be given to [`ClientBuilder::roblosecurity`](crate::ClientBuilder::roblosecurity)
+    /// to construct a client for that session.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Returns the roblosecurity (without the `.ROBLOSECURITY=` prefix) taken from the `set-cookie` header.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::MalformedResponse`] - If the roblosecurity cookie is not returned.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let ticket = client.authentication_ticket().await?;
+    ///
+    /// let unauthenticated_client = ClientBuilder::new().build();
+    /// let new_roblosecurity = unauthenticated_client
+    ///     .redeem_authentication_ticket(ticket)
+    ///     .await?;
+    ///
+    /// let new_client = ClientBuilder::new().roblosecurity(new_roblosecurity).build();
+    /// println!("Redeemed ticket for {}", new_client.username().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn redeem_authentication_ticket(
+        &self,
+        ticket: String,
+    ) -> Result<String, RoboatError> {
+        match self
+            .redeem_authentication_ticket_internal(ticket.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.redeem_authentication_ticket_internal(ticket).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Revokes every session of the authenticated account except a new one that replaces
+    /// the current session, using <https://auth.roblox.com/v2/logoutfromallsessionsandreauthenticate>.
+    ///
+    /// To revoke a single session, use [`Client::revoke_session`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The old roblosecurity is invalidated, so the client switches to the new roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * Returns the new roblosecurity taken from the `set-cookie` header, so that it can be saved.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::MalformedResponse`] - If the roblosecurity cookie is not returned.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let new_roblosecurity = client.revoke_other_sessions().await?;
+    ///
+    /// // The client already uses the new roblosecurity, which should be saved for later.
+    /// println!("Revoked all other sessions of {}", client.username().await?);
+    /// println!("New Roblosecurity: {}", new_roblosecurity);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn revoke_other_sessions(&self) -> Result<String, RoboatError> {
+        match self.revoke_other_sessions_internal().await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.revoke_other_sessions_internal().await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Logs in with a username and password using <https://auth.roblox.com/v2/login>,
+    /// and builds a [`Client`] with the issued roblosecurity.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The user information of the returned client is already cached.
+    ///
+    /// # Return Value Notes
+    /// * If the account has two step verification enabled, [`RoboatError::TwoStepVerificationRequired`]
+    ///   is returned. The login can be resumed with [`ClientBuilder::login_two_step`].
+    /// * If Roblox requires a captcha, [`RoboatError::LoginChallengeRequired`] is returned. Once the
+    ///   challenge is solved, the login can be resumed with [`ClientBuilder::login_with_challenge`].
+    /// * An incorrect username or password is returned as [`RoboatError::UnknownRobloxErrorCode`]
+    ///   with a code of 1.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](Client#standard-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](Client#x-csrf-token-required-errors).
+    /// * [`RoboatError::TwoStepVerificationRequired`]
+    /// * [`RoboatError::LoginChallengeRequired`]
+    /// * [`RoboatError::MalformedResponse`] - If the roblosecurity cookie is not returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::{ClientBuilder, RoboatError};
+    ///
+    /// const USERNAME: &str = "username";
+    /// const PASSWORD: &str = "password";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let result = ClientBuilder::new()
+    ///     .login(USERNAME.to_string(), PASSWORD.to_string())
+    ///     .await;
+    ///
+    /// let client = match result {
+    ///     Ok(client) => client,
+    ///     Err(RoboatError::TwoStepVerificationRequired(challenge)) => {
+    ///         let code = "123456".to_string();
+    ///
+    ///         ClientBuilder::new().login_two_step(challenge, code).await?
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// };
+    ///
+    /// println!("Logged in as {}", client.username().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login(self, username: String, password: String) -> Result<Client, RoboatError> {
+        self.login_with_challenge_opt(username, password, None)
+            .await
+    }
+
+    /// Logs in with a username and password using <https://auth.roblox.com/v2/login>, passing
+    /// along a solved challenge returned from a previous login attempt.
+    ///
+    /// This is the same as [`ClientBuilder::login`], except that the `rblx-challenge-*`
+    /// headers are sent with the request.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * `challenge` is the [`ChallengeInfo`] from [`RoboatError::LoginChallengeRequired`], with
+    ///   `challenge_metadata` replaced by the base64 encoded metadata of the solved challenge.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](Client#standard-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](Client#x-csrf-token-required-errors).
+    /// * [`RoboatError::TwoStepVerificationRequired`]
+    /// * [`RoboatError::LoginChallengeRequired`]
+    /// * [`RoboatError::MalformedResponse`] - If the roblosecurity cookie is not returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::{ClientBuilder, RoboatError};
+    ///
+    /// const USERNAME: &str = "username";
+    /// const PASSWORD: &str = "password";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let result = ClientBuilder::new()
+    ///     .login(USERNAME.to_string(), PASSWORD.to_string())
+    ///     .await;
+    ///
+    /// if let Err(RoboatError::LoginChallengeRequired(mut challenge)) = result {
+    ///     // Solve the challenge here.
+    ///     challenge.challenge_metadata = "solved-challenge-metadata".to_string();
+    ///
+    ///     let client = ClientBuilder::new()
+    ///         .login_with_challenge(USERNAME.to_string(), PASSWORD.to_string(), challenge)
+    ///         .await?;
+    ///
+    ///     println!("Logged in as {}", client.username().await?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login_with_challenge(
+        self,
+        username: String,
+        password: String,
+        challenge: ChallengeInfo,
+    ) -> Result<Client, RoboatError> {
+        self.login_with_challenge_opt(username, password, Some(challenge))
+            .await
+    }
+
+    /// Resumes a login that requires two step verification using
+    /// <https://twostepverification.roblox.com/v1/users/{user_id}/challenges/{media_type}/verify> and
+    /// <https://auth.roblox.com/v3/users/{user_id}/two-step-verification/login>, and builds a
+    /// [`Client`] with the issued roblosecurity.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The user information of the returned client is already cached.
+    ///
+    /// # Argument Notes
+    /// * `challenge` is the [`TwoStepVerificationChallenge`] from [`RoboatError::TwoStepVerificationRequired`].
+    /// * `code` is the code sent to the user (or generated by their authenticator).
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](Client#standard-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](Client#x-csrf-token-required-errors).
+    /// * [`RoboatError::MalformedResponse`] - If the roblosecurity cookie is not returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::{ClientBuilder, RoboatError};
+    ///
+    /// const USERNAME: &str = "username";
+    /// const PASSWORD: &str = "password";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let result = ClientBuilder::new()
+    ///     .login(USERNAME.to_string(), PASSWORD.to_string())
+    ///     .await;
+    ///
+    /// if let Err(RoboatError::TwoStepVerificationRequired(challenge)) = result {
+    ///     let code = "123456".to_string();
+    ///
+    ///     let client = ClientBuilder::new().login_two_step(challenge, code).await?;
+    ///
+    ///     println!("Logged in as {}", client.username().await?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login_two_step(
+        self,
+        challenge: TwoStepVerificationChallenge,
+        code: String,
+    ) -> Result<Client, RoboatError> {
+        let login_client = self.clone().build();
+
+        let verification_token = match login_client
+            .verify_two_step_code_internal(&challenge, &code)
+            .await
+        {
+            Ok(x) => x,
+            Err(RoboatError::InvalidXcsrf(new_xcsrf)) => {
+                login_client.set_xcsrf(new_xcsrf).await;
+
+                login_client
+                    .verify_two_step_code_internal(&challenge, &code)
+                    .await?
+            }
+            Err(e) => return Err(e),
+        };
+
+        let roblosecurity = match login_client
+            .two_step_login_internal(&challenge, &verification_token)
+            .await
+        {
+            Ok(x) => x,
+            Err(RoboatError::InvalidXcsrf(new_xcsrf)) => {
+                login_client.set_xcsrf(new_xcsrf).await;
+
+                login_client
+                    .two_step_login_internal(&challenge, &verification_token)
+                    .await?
+            }
+            Err(e) => return Err(e),
+        };
+
+        let client = self.roblosecurity(roblosecurity).build();
+        client.set_xcsrf(login_client.xcsrf().await).await;
+
+        // The two step login response does not include the user information, so it is
+        // fetched (and cached) with the new roblosecurity.
+        client.user_information_internal().await?;
+
+        Ok(client)
+    }
+
+    async fn login_with_challenge_opt(
+        self,
+        username: String,
+        password: String,
+        challenge: Option<ChallengeInfo>,
+    ) -> Result<Client, RoboatError> {
+        // A client without a roblosecurity is used to send the login requests.
+        let login_client = self.clone().build();
+
+        let (roblosecurity, user_information) = match login_client
+            .login_internal(&username, &password, challenge.as_ref())
+            .await
+        {
+            Ok(x) => x,
+            Err(RoboatError::InvalidXcsrf(new_xcsrf)) => {
+                login_client.set_xcsrf(new_xcsrf).await;
+
+                login_client
+                    .login_internal(&username, &password, challenge.as_ref())
+                    .await?
+            }
+            Err(e) => return Err(e),
+        };
+
+        let client = self.roblosecurity(roblosecurity).build();
+        client.set_xcsrf(login_client.xcsrf().await).await;
+        client.set_user_information(user_information).await;
+
+        Ok(client)
+    }
+}
+
+/// Builds a [`ChallengeInfo`] from the `rblx-challenge-*` headers of a response, if they exist.
+fn challenge_info_from_headers(headers: &HeaderMap) -> Option<Result<ChallengeInfo, RoboatError>> {
+    let header_string = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|x| x.to_str().ok())
+            .map(|x| x.to_string())
+    };
+
+    let challenge_id = header_string(CHALLENGE_ID_HEADER)?;
+
+    let challenge_info = match (
+        header_string(CHALLENGE_TYPE_HEADER),
+        header_string(CHALLENGE_METADATA_HEADER),
+    ) {
+        (Some(challenge_type), Some(challenge_metadata)) => ChallengeType::try_from(challenge_type)
+            .map(|challenge_type| ChallengeInfo {
+                challenge_id,
+                challenge_metadata,
+                challenge_type,
+            })
+            .map_err(|_| RoboatError::UnknownStatus403Format),
+        _ => Err(RoboatError::UnknownStatus403Format),
+    };
+
+    Some(challenge_info)
+}
+
+/// Pulls the roblosecurity out of the `set-cookie` headers of a response.
+pub(crate) fn roblosecurity_from_headers(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .filter_map(|x| x.to_str().ok())
+        .find_map(|x| x.strip_prefix(ROBLOSECURITY_COOKIE_PREFIX))
+        .map(|x| x.split(';').next().unwrap_or_default().to_string())
+}
+
+mod internal {
+    use super::{
+        challenge_info_from_headers, request_types, roblosecurity_from_headers,
+        ClientUserInformation, TwoStepVerificationChallenge, AUTHENTICATION_NEGOTIATION_HEADER,
+        AUTHENTICATION_NEGOTIATION_VALUE, AUTHENTICATION_TICKET_API, AUTHENTICATION_TICKET_HEADER,
+        CHALLENGE_ID_HEADER, CHALLENGE_METADATA_HEADER, CHALLENGE_TYPE_HEADER, LOGIN_API,
+        LOGIN_CREDENTIAL_TYPE, LOGOUT_FROM_ALL_SESSIONS_API, REDEEM_AUTHENTICATION_TICKET_API,
+        TWO_STEP_ACTION_TYPE, TWO_STEP_LOGIN_API, VERIFY_TWO_STEP_CODE_API,
+    };
+    use crate::{ChallengeInfo, Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn authentication_ticket_internal(&self) -> Result<String, RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .post(AUTHENTICATION_TICKET_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .header(
+                    AUTHENTICATION_NEGOTIATION_HEADER,
+                    AUTHENTICATION_NEGOTIATION_VALUE,
+                )
+                .json(&serde_json::json!({}))
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+
+            let ticket = response
+                .headers()
+                .get(AUTHENTICATION_TICKET_HEADER)
+                .and_then(|x| x.to_str().ok())
+                .ok_or(RoboatError::MalformedResponse)?;
+
+            Ok(ticket.to_string())
+        }
+
+        pub(super) async fn redeem_authentication_ticket_internal(
+            &self,
+            ticket: String,
+        ) -> Result<String, RoboatError> {
+            let body = request_types::RedeemAuthenticationTicketReqBody {
+                authentication_ticket: ticket,
+            };
+
+            let request_result = self
+                .reqwest_client
+                .post(REDEEM_AUTHENTICATION_TICKET_API)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .header(
+                    AUTHENTICATION_NEGOTIATION_HEADER,
+                    AUTHENTICATION_NEGOTIATION_VALUE,
+                )
+                .json(&body)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+
+            roblosecurity_from_headers(response.headers()).ok_or(RoboatError::MalformedResponse)
+        }
+
+        pub(super) async fn revoke_other_sessions_internal(&self) -> Result<String, RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .post(LOGOUT_FROM_ALL_SESSIONS_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&serde_json::json!({}))
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+
+            let roblosecurity = roblosecurity_from_headers(response.headers())
+                .ok_or(RoboatError::MalformedResponse)?;
+
+            // The old roblosecurity no longer works, so it is replaced.
+            self.set_roblosecurity(&roblosecurity);
+
+            Ok(roblosecurity)
+        }
+
+        pub(super) async fn login_internal(
+            &self,
+            username: &str,
+            password: &str,
+            challenge: Option<&ChallengeInfo>,
+        ) -> Result<(String, ClientUserInformation), RoboatError> {
+            let body = request_types::LoginReqBody {
+                ctype: LOGIN_CREDENTIAL_TYPE.to_string(),
+                cvalue: username.to_string(),
+                password: password.to_string(),
+            };
+
+            let mut builder = self
+                .reqwest_client
+                .post(LOGIN_API)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            if let Some(challenge) = challenge {
+                builder = builder
+                    .header(CHALLENGE_ID_HEADER, &challenge.challenge_id)
+                    .header(CHALLENGE_TYPE_HEADER, challenge.challenge_type.to_string())
+                    .header(CHALLENGE_METADATA_HEADER, &challenge.challenge_metadata);
+            }
+
+            let request_result = builder.send().await;
+
+            // Captcha challenges come back as a 403 with the challenge in the headers, which
+            // needs to be caught before the response is consumed.
+            if let Ok(response) = &request_result {
+                if response.status().as_u16() == 403 {
+                    if let Some(challenge_info) = challenge_info_from_headers(response.headers()) {
+                        return Err(RoboatError::LoginChallengeRequired(challenge_info?));
+                    }
+                }
+            }
+
+            let response = Self::validate_request_result(request_result).await?;
+            let roblosecurity = roblosecurity_from_headers(response.headers());
+            let raw = Self::parse_to_raw::<request_types::LoginResponse>(response).await?;
+
+            if let Some(two_step) = raw.two_step_verification_data {
+                return Err(RoboatError::TwoStepVerificationRequired(
+                    TwoStepVerificationChallenge {
+                        user_id: raw.user.id,
+                        challenge_id: two_step.ticket,
+                        media_type: two_step.media_type,
+                    },
+                ));
+            }
+
+            let roblosecurity = roblosecurity.ok_or(RoboatError::MalformedResponse)?;
+
+            let user_information = ClientUserInformation {
+                user_id: raw.user.id,
+                username: raw.user.name,
+                display_name: raw.user.display_name,
+            };
+
+            Ok((roblosecurity, user_information))
+        }
+
+        pub(super) async fn verify_two_step_code_internal(
+            &self,
+            challenge: &TwoStepVerificationChallenge,
+            code: &str,
+        ) -> Result<String, RoboatError> {
+            let formatted_url = VERIFY_TWO_STEP_CODE_API
+                .replace("{user_id}", &challenge.user_id.to_string())
+                .replace("{media_type}", &challenge.media_type.to_string());
+
+            let body = request_types::VerifyTwoStepCodeReqBody {
+                challenge_id: challenge.challenge_id.clone(),
+                action_type: TWO_STEP_ACTION_TYPE.to_string(),
+                code: code.to_string(),
+            };
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw =
+                Self::parse_to_raw::<request_types::VerifyTwoStepCodeResponse>(response).await?;
+
+            Ok(raw.verification_token)
+        }
+
+        pub(super) async fn two_step_login_internal(
+            &self,
+            challenge: &TwoStepVerificationChallenge,
+            verification_token: &str,
+        ) -> Result<String, RoboatError> {
+            let formatted_url =
+                TWO_STEP_LOGIN_API.replace("{user_id}", &challenge.user_id.to_string());
+
+            let body = request_types::TwoStepLoginReqBody {
+                challenge_id: challenge.challenge_id.clone(),
+                verification_token: verification_token.to_string(),
+                remember_device: false,
+            };
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+
+            roblosecurity_from_headers(response.headers()).ok_or(RoboatError::MalformedResponse)
+        }
+    }
 }
@@ -0,0 +1,59 @@
+use super::TwoStepVerificationMediaType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RedeemAuthenticationTicketReqBody {
+    pub authentication_ticket: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct LoginReqBody {
+    pub ctype: String,
+    pub cvalue: String,
+    pub password: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct LoginResponse {
+    pub user: LoginUserRaw,
+    pub two_step_verification_data: Option<TwoStepVerificationDataRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct LoginUserRaw {
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TwoStepVerificationDataRaw {
+    pub media_type: TwoStepVerificationMediaType,
+    pub ticket: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct VerifyTwoStepCodeReqBody {
+    pub challenge_id: String,
+    pub action_type: String,
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct VerifyTwoStepCodeResponse {
+    pub verification_token: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TwoStepLoginReqBody {
+    pub challenge_id: String,
+    pub verification_token: String,
+    pub remember_device: bool,
+}
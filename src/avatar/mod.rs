@@ -0,0 +1,1278 @@
+use crate::catalog::AssetType;
+use crate::{Client, Limit, RoboatError};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const USER_AVATAR_API: &str = "https://avatar.roblox.com/v2/avatar/users/{user_id}/avatar";
+const SET_WEARING_ASSETS_API: &str = "https://avatar.roblox.com/v2/avatar/set-wearing-assets";
+const WEAR_ASSET_API: &str = "https://avatar.roblox.com/v1/avatar/assets/{asset_id}/wear";
+const REMOVE_ASSET_API: &str = "https://avatar.roblox.com/v1/avatar/assets/{asset_id}/remove";
+const SET_BODY_COLORS_API: &str = "https://avatar.roblox.com/v2/avatar/set-body-colors";
+const SET_SCALES_API: &str = "https://avatar.roblox.com/v1/avatar/set-scales";
+const SET_AVATAR_TYPE_API: &str = "https://avatar.roblox.com/v1/avatar/set-player-avatar-type";
+const REDRAW_THUMBNAIL_API: &str = "https://avatar.roblox.com/v1/avatar/redraw-thumbnail";
+const USER_OUTFITS_API: &str = "https://avatar.roblox.com/v2/avatar/users/{user_id}/outfits?itemsPerPage={limit}&paginationToken={cursor}";
+/// The most outfits <https://avatar.roblox.com/v2/avatar/users/{user_id}/outfits> returns per page.
+const USER_OUTFITS_MAX_LIMIT: u64 = 50;
+const OUTFIT_DETAILS_API: &str = "https://avatar.roblox.com/v3/outfits/{outfit_id}/details";
+const CREATE_OUTFIT_API: &str = "https://avatar.roblox.com/v3/outfits/create";
+const UPDATE_OUTFIT_API: &str = "https://avatar.roblox.com/v3/outfits/{outfit_id}";
+const DELETE_OUTFIT_API: &str = "https://avatar.roblox.com/v1/outfits/{outfit_id}/delete";
+
+/// The only outfit type that can be created by users. Other types are bundle outfits.
+const OUTFIT_TYPE: &str = "Avatar";
+
+/// Custom Roblox errors that occur when editing the avatar of the authenticated user.
+#[non_exhaustive]
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum AvatarError {
+    /// Thrown when the user tries to wear an asset they do not own.
+    #[default]
+    #[error("Asset Not Owned")]
+    AssetNotOwned,
+    /// Thrown when Roblox rejects some of the assets being worn, such as when
+    /// too many accessories of one type are worn at once. Contains the ids of the rejected assets.
+    #[error("Invalid Asset Combination: {0:?}")]
+    InvalidAssetCombination(Vec<u64>),
+    /// Thrown when Roblox does not take off an asset, such as when it is not being worn.
+    /// Contains the id of the asset.
+    #[error("Asset Not Removed: {0}")]
+    AssetNotRemoved(u64),
+}
+
+/// The rig type of an avatar.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum AvatarType {
+    R6,
+    #[default]
+    R15,
+}
+
+/// The body colors of an avatar, as hex color strings (such as `"F8F8F8"`).
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct BodyColors {
+    #[serde(rename = "headColor3")]
+    pub head: String,
+    #[serde(rename = "torsoColor3")]
+    pub torso: String,
+    #[serde(rename = "rightArmColor3")]
+    pub right_arm: String,
+    #[serde(rename = "leftArmColor3")]
+    pub left_arm: String,
+    #[serde(rename = "rightLegColor3")]
+    pub right_leg: String,
+    #[serde(rename = "leftLegColor3")]
+    pub left_leg: String,
+}
+
+/// The scales of an avatar. These only apply to R15 avatars.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct AvatarScales {
+    pub height: f64,
+    pub width: f64,
+    pub head: f64,
+    pub depth: f64,
+    pub proportion: f64,
+    pub body_type: f64,
+}
+
+/// An asset worn by an avatar.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct WornAsset {
+    /// The id of the asset.
+    pub asset_id: u64,
+    /// The name of the asset.
+    pub name: String,
+    /// The type of the asset. This is `None` if the asset type is not known to this crate.
+    pub asset_type: Option<AssetType>,
+    /// The version of the asset being worn.
+    pub current_version_id: Option<u64>,
+}
+
+/// An emote equipped by an avatar.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct EquippedEmote {
+    /// The asset id of the emote.
+    pub asset_id: u64,
+    /// The name of the emote.
+    pub name: String,
+    /// The slot the emote is equipped in (1-8).
+    pub position: u8,
+}
+
+/// The avatar a user is currently wearing. Retrieved using [`Client::avatar`].
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct Avatar {
+    /// Whether the avatar is R6 or R15.
+    pub avatar_type: AvatarType,
+    /// The body colors of the avatar.
+    pub body_colors: BodyColors,
+    /// The scales of the avatar.
+    pub scales: AvatarScales,
+    /// The assets being worn.
+    pub assets: Vec<WornAsset>,
+    /// The emotes equipped.
+    pub emotes: Vec<EquippedEmote>,
+    /// Whether Roblox applied a default shirt because the avatar is not wearing one.
+    pub default_shirt_applied: bool,
+    /// Whether Roblox applied default pants because the avatar is not wearing any.
+    pub default_pants_applied: bool,
+}
+
+/// A user-created outfit. Retrieved using [`Client::outfits`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Outfit {
+    /// The id of the outfit.
+    pub outfit_id: u64,
+    /// The name of the outfit.
+    pub name: String,
+    /// Whether the outfit can be edited by its owner.
+    pub is_editable: bool,
+}
+
+/// The full contents of an outfit. Retrieved using [`Client::outfit_details`].
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct OutfitDetails {
+    /// The id of the outfit.
+    pub outfit_id: u64,
+    /// The name of the outfit.
+    pub name: String,
+    /// The assets in the outfit.
+    pub assets: Vec<WornAsset>,
+    /// The body colors of the outfit.
+    pub body_colors: BodyColors,
+    /// The scales of the outfit.
+    pub scales: AvatarScales,
+    /// Whether the outfit is R6 or R15.
+    pub avatar_type: AvatarType,
+    /// Whether the outfit can be edited by its owner.
+    pub is_editable: bool,
+}
+
+/// The contents used to create or update an outfit with [`Client::create_outfit`]
+/// and [`Client::update_outfit`].
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct OutfitPayload {
+    /// The name of the outfit.
+    pub name: String,
+    /// The ids of the assets in the outfit.
+    pub asset_ids: Vec<u64>,
+    /// The body colors of the outfit.
+    pub body_colors: BodyColors,
+    /// The scales of the outfit.
+    pub scales: AvatarScales,
+    /// Whether the outfit is R6 or R15.
+    pub avatar_type: AvatarType,
+}
+
+impl From<request_types::AssetRaw> for WornAsset {
+    fn from(raw: request_types::AssetRaw) -> Self {
+        Self {
+            asset_id: raw.id,
+            name: raw.name,
+            asset_type: AssetType::try_from(raw.asset_type.id).ok(),
+            current_version_id: raw.current_version_id,
+        }
+    }
+}
+
+impl From<request_types::OutfitDetailsResponse> for OutfitDetails {
+    fn from(raw: request_types::OutfitDetailsResponse) -> Self {
+        Self {
+            outfit_id: raw.id,
+            name: raw.name,
+            assets: raw.assets.into_iter().map(WornAsset::from).collect(),
+            body_colors: raw.body_color3s,
+            scales: raw.scale,
+            avatar_type: raw.player_avatar_type,
+            is_editable: raw.is_editable,
+        }
+    }
+}
+
+impl From<OutfitPayload> for request_types::OutfitReqBody {
+    fn from(payload: OutfitPayload) -> Self {
+        Self {
+            name: payload.name,
+            body_color3s: payload.body_colors,
+            assets: payload
+                .asset_ids
+                .into_iter()
+                .map(|id| request_types::WearingAssetReq { id })
+                .collect(),
+            scale: payload.scales,
+            player_avatar_type: payload.avatar_type,
+            outfit_type: OUTFIT_TYPE.to_string(),
+        }
+    }
+}
+
+impl From<request_types::AvatarResponse> for Avatar {
+    fn from(raw: request_types::AvatarResponse) -> Self {
+        let assets = raw.assets.into_iter().map(WornAsset::from).collect();
+
+        let emotes = raw
+            .emotes
+            .into_iter()
+            .map(|emote| EquippedEmote {
+                asset_id: emote.asset_id,
+                name: emote.asset_name,
+                position: emote.position,
+            })
+            .collect();
+
+        Self {
+            avatar_type: raw.player_avatar_type,
+            body_colors: raw.body_color3s,
+            scales: raw.scales,
+            assets,
+            emotes,
+            default_shirt_applied: raw.default_shirt_applied,
+            default_pants_applied: raw.default_pants_applied,
+        }
+    }
+}
+
+impl Client {
+    /// Fetches the avatar a user is currently wearing using
+    /// <https://avatar.roblox.com/v2/avatar/users/{user_id}/avatar>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let avatar = client.avatar(USER_ID).await?;
+    ///
+    /// println!("Avatar Type: {:?}", avatar.avatar_type);
+    ///
+    /// for asset in avatar.assets {
+    ///     println!("Wearing {} ({:?})", asset.name, asset.asset_type);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn avatar(&self, user_id: u64) -> Result<Avatar, RoboatError> {
+        let formatted_url = USER_AVATAR_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::AvatarResponse>(response).await?;
+
+        Ok(Avatar::from(raw))
+    }
+
+    /// Replaces every asset worn by the authenticated user using
+    /// <https://avatar.roblox.com/v2/avatar/set-wearing-assets>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Any asset not included in `asset_ids` is taken off.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::AvatarError`] - Nested inside this error, all variants of [`AvatarError`] may be thrown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_ids = vec![1365767, 63690008];
+    /// client.set_worn_assets(asset_ids).await?;
+    ///
+    /// println!("Successfully updated worn assets!");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_worn_assets(&self, asset_ids: Vec<u64>) -> Result<(), RoboatError> {
+        match self.set_worn_assets_internal(asset_ids.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_worn_assets_internal(asset_ids).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Puts on a single asset for the authenticated user using
+    /// <https://avatar.roblox.com/v1/avatar/assets/{asset_id}/wear>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::AvatarError`] - Nested inside this error, all variants of [`AvatarError`] may be thrown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const ASSET_ID: u64 = 1365767;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.wear_asset(ASSET_ID).await?;
+    ///
+    /// println!("Now wearing asset {}!", ASSET_ID);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wear_asset(&self, asset_id: u64) -> Result<(), RoboatError> {
+        match self.wear_asset_internal(asset_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.wear_asset_internal(asset_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Takes off a single asset for the authenticated user using
+    /// <https://avatar.roblox.com/v1/avatar/assets/{asset_id}/remove>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::AvatarError`] - Nested inside this error, [`AvatarError::AssetNotRemoved`] may be thrown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const ASSET_ID: u64 = 1365767;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.unwear_asset(ASSET_ID).await?;
+    ///
+    /// println!("No longer wearing asset {}!", ASSET_ID);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unwear_asset(&self, asset_id: u64) -> Result<(), RoboatError> {
+        match self.unwear_asset_internal(asset_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.unwear_asset_internal(asset_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Sets the body colors of the authenticated user using
+    /// <https://avatar.roblox.com/v2/avatar/set-body-colors>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * Colors are hex strings without a leading `#` (such as `"F8F8F8"`).
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::avatar::BodyColors;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let white = "F8F8F8".to_string();
+    ///
+    /// let body_colors = BodyColors {
+    ///     head: white.clone(),
+    ///     torso: white.clone(),
+    ///     right_arm: white.clone(),
+    ///     left_arm: white.clone(),
+    ///     right_leg: white.clone(),
+    ///     left_leg: white,
+    /// };
+    ///
+    /// client.set_body_colors(body_colors).await?;
+    ///
+    /// println!("Successfully set body colors!");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_body_colors(&self, body_colors: BodyColors) -> Result<(), RoboatError> {
+        match self.set_body_colors_internal(&body_colors).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_body_colors_internal(&body_colors).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Sets the scales of the authenticated user's avatar using
+    /// <https://avatar.roblox.com/v1/avatar/set-scales>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Scales only affect R15 avatars.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::avatar::AvatarScales;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let scales = AvatarScales {
+    ///     height: 1.0,
+    ///     width: 1.0,
+    ///     head: 1.0,
+    ///     depth: 1.0,
+    ///     proportion: 0.0,
+    ///     body_type: 0.0,
+    /// };
+    ///
+    /// client.set_avatar_scales(scales).await?;
+    ///
+    /// println!("Successfully set avatar scales!");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_avatar_scales(&self, scales: AvatarScales) -> Result<(), RoboatError> {
+        match self.set_avatar_scales_internal(scales).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_avatar_scales_internal(scales).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Sets whether the authenticated user's avatar is R6 or R15 using
+    /// <https://avatar.roblox.com/v1/avatar/set-player-avatar-type>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::avatar::AvatarType;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.set_avatar_type(AvatarType::R6).await?;
+    ///
+    /// println!("Avatar is now R6!");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_avatar_type(&self, avatar_type: AvatarType) -> Result<(), RoboatError> {
+        match self.set_avatar_type_internal(avatar_type).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_avatar_type_internal(avatar_type).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Requests Roblox to redraw the authenticated user's avatar thumbnail using
+    /// <https://avatar.roblox.com/v1/avatar/redraw-thumbnail>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Roblox heavily rate limits this endpoint.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.redraw_avatar_thumbnail().await?;
+    ///
+    /// println!("Requested a thumbnail redraw!");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn redraw_avatar_thumbnail(&self) -> Result<(), RoboatError> {
+        match self.redraw_avatar_thumbnail_internal().await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.redraw_avatar_thumbnail_internal().await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Fetches a page of the outfits created by a user using
+    /// <https://avatar.roblox.com/v2/avatar/users/{user_id}/outfits>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Argument Notes
+    /// * If `is_editable` is `Some(true)`, only user-created outfits are returned. If it is
+    ///   `Some(false)`, only outfits that come with bundles are returned. All outfits are returned if it is `None`.
+    /// * The endpoint returns at most 50 outfits per page, so [`Limit::Hundred`] is treated as 50.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of outfits.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (outfits, next_cursor) = client
+    ///         .outfits(USER_ID, Some(true), Limit::Fifty, cursor)
+    ///         .await?;
+    ///
+    ///     for outfit in outfits {
+    ///         println!("Outfit: {} / ID: {}", outfit.name, outfit.outfit_id);
+    ///     }
+    ///
+    ///     cursor = next_cursor;
+    ///
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn outfits(
+        &self,
+        user_id: u64,
+        is_editable: Option<bool>,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Outfit>, Option<String>), RoboatError> {
+        let limit = limit.to_u64().min(USER_OUTFITS_MAX_LIMIT);
+
+        let mut formatted_url = USER_OUTFITS_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{limit}", &limit.to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        if let Some(is_editable) = is_editable {
+            formatted_url.push_str(&format!("&isEditable={}", is_editable));
+        }
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::OutfitsResponse>(response).await?;
+
+        let outfits = raw
+            .data
+            .into_iter()
+            .map(|outfit| Outfit {
+                outfit_id: outfit.id,
+                name: outfit.name,
+                is_editable: outfit.is_editable,
+            })
+            .collect();
+
+        // Roblox returns an empty string instead of null on the last page.
+        let next_cursor = raw.pagination_token.filter(|token| !token.is_empty());
+
+        Ok((outfits, next_cursor))
+    }
+
+    /// Fetches the contents of an outfit using <https://avatar.roblox.com/v3/outfits/{outfit_id}/details>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const OUTFIT_ID: u64 = 1234567890;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let details = client.outfit_details(OUTFIT_ID).await?;
+    ///
+    /// println!("Outfit {} has {} assets.", details.name, details.assets.len());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn outfit_details(&self, outfit_id: u64) -> Result<OutfitDetails, RoboatError> {
+        let formatted_url = OUTFIT_DETAILS_API.replace("{outfit_id}", &outfit_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::OutfitDetailsResponse>(response).await?;
+
+        Ok(OutfitDetails::from(raw))
+    }
+
+    /// Creates an outfit for the authenticated user using <https://avatar.roblox.com/v3/outfits/create>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Returns the id of the new outfit.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::avatar::OutfitPayload;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// // Save the current avatar as an outfit.
+    /// let user_id = client.user_id().await?;
+    /// let avatar = client.avatar(user_id).await?;
+    ///
+    /// let payload = OutfitPayload {
+    ///     name: "My Outfit".to_string(),
+    ///     asset_ids: avatar.assets.iter().map(|asset| asset.asset_id).collect(),
+    ///     body_colors: avatar.body_colors,
+    ///     scales: avatar.scales,
+    ///     avatar_type: avatar.avatar_type,
+    /// };
+    ///
+    /// let outfit_id = client.create_outfit(payload).await?;
+    ///
+    /// println!("Created outfit {}", outfit_id);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_outfit(&self, payload: OutfitPayload) -> Result<u64, RoboatError> {
+        match self.create_outfit_internal(payload.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.create_outfit_internal(payload).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Replaces the contents of an outfit owned by the authenticated user using
+    /// <https://avatar.roblox.com/v3/outfits/{outfit_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::avatar::OutfitPayload;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const OUTFIT_ID: u64 = 1234567890;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let details = client.outfit_details(OUTFIT_ID).await?;
+    ///
+    /// // Remove the first asset from the outfit.
+    /// let payload = OutfitPayload {
+    ///     name: details.name,
+    ///     asset_ids: details.assets.iter().skip(1).map(|asset| asset.asset_id).collect(),
+    ///     body_colors: details.body_colors,
+    ///     scales: details.scales,
+    ///     avatar_type: details.avatar_type,
+    /// };
+    ///
+    /// client.update_outfit(OUTFIT_ID, payload).await?;
+    ///
+    /// println!("Updated outfit {}", OUTFIT_ID);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_outfit(
+        &self,
+        outfit_id: u64,
+        payload: OutfitPayload,
+    ) -> Result<(), RoboatError> {
+        match self
+            .update_outfit_internal(outfit_id, payload.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.update_outfit_internal(outfit_id, payload).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Renames an outfit owned by the authenticated user using
+    /// <https://avatar.roblox.com/v3/outfits/{outfit_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const OUTFIT_ID: u64 = 1234567890;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.rename_outfit(OUTFIT_ID, "New Name".to_string()).await?;
+    ///
+    /// println!("Renamed outfit {}", OUTFIT_ID);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rename_outfit(&self, outfit_id: u64, name: String) -> Result<(), RoboatError> {
+        match self.rename_outfit_internal(outfit_id, name.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.rename_outfit_internal(outfit_id, name).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Deletes an outfit owned by the authenticated user using
+    /// <https://avatar.roblox.com/v1/outfits/{outfit_id}/delete>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const OUTFIT_ID: u64 = 1234567890;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.delete_outfit(OUTFIT_ID).await?;
+    ///
+    /// println!("Deleted outfit {}", OUTFIT_ID);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_outfit(&self, outfit_id: u64) -> Result<(), RoboatError> {
+        match self.delete_outfit_internal(outfit_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.delete_outfit_internal(outfit_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+mod internal {
+    use super::{
+        request_types, AvatarError, AvatarScales, AvatarType, BodyColors, OutfitPayload,
+        CREATE_OUTFIT_API, DELETE_OUTFIT_API, REDRAW_THUMBNAIL_API, REMOVE_ASSET_API,
+        SET_AVATAR_TYPE_API, SET_BODY_COLORS_API, SET_SCALES_API, SET_WEARING_ASSETS_API,
+        UPDATE_OUTFIT_API, WEAR_ASSET_API,
+    };
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn set_worn_assets_internal(
+            &self,
+            asset_ids: Vec<u64>,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let body = request_types::SetWearingAssetsReqBody {
+                assets: asset_ids
+                    .into_iter()
+                    .map(|id| request_types::WearingAssetReq { id })
+                    .collect(),
+            };
+
+            let request_result = self
+                .reqwest_client
+                .post(SET_WEARING_ASSETS_API)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw =
+                Self::parse_to_raw::<request_types::SetWearingAssetsResponse>(response).await?;
+
+            match raw.success {
+                true => Ok(()),
+                false => Err(RoboatError::AvatarError(
+                    AvatarError::InvalidAssetCombination(raw.invalid_asset_ids),
+                )),
+            }
+        }
+
+        pub(super) async fn wear_asset_internal(&self, asset_id: u64) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let formatted_url = WEAR_ASSET_API.replace("{asset_id}", &asset_id.to_string());
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let response = match Self::validate_request_result(request_result).await {
+                Ok(x) => x,
+                Err(RoboatError::UnknownRobloxErrorCode { code, message }) => match code {
+                    3 => return Err(RoboatError::AvatarError(AvatarError::AssetNotOwned)),
+                    _ => return Err(RoboatError::UnknownRobloxErrorCode { code, message }),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let raw = Self::parse_to_raw::<request_types::SuccessResponse>(response).await?;
+
+            match raw.success {
+                true => Ok(()),
+                false => Err(RoboatError::AvatarError(
+                    AvatarError::InvalidAssetCombination(vec![asset_id]),
+                )),
+            }
+        }
+
+        pub(super) async fn unwear_asset_internal(&self, asset_id: u64) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let formatted_url = REMOVE_ASSET_API.replace("{asset_id}", &asset_id.to_string());
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let response = match Self::validate_request_result(request_result).await {
+                Ok(x) => x,
+                Err(RoboatError::UnknownRobloxErrorCode { code, message }) => match code {
+                    3 => return Err(RoboatError::AvatarError(AvatarError::AssetNotOwned)),
+                    _ => return Err(RoboatError::UnknownRobloxErrorCode { code, message }),
+                },
+                Err(e) => return Err(e),
+            };
+
+            let raw = Self::parse_to_raw::<request_types::SuccessResponse>(response).await?;
+
+            match raw.success {
+                true => Ok(()),
+                false => Err(RoboatError::AvatarError(AvatarError::AssetNotRemoved(
+                    asset_id,
+                ))),
+            }
+        }
+
+        pub(super) async fn set_body_colors_internal(
+            &self,
+            body_colors: &BodyColors,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .post(SET_BODY_COLORS_API)
+                .json(body_colors)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_avatar_scales_internal(
+            &self,
+            scales: AvatarScales,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .post(SET_SCALES_API)
+                .json(&scales)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_avatar_type_internal(
+            &self,
+            avatar_type: AvatarType,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let body = request_types::SetPlayerAvatarTypeReqBody {
+                player_avatar_type: avatar_type,
+            };
+
+            let request_result = self
+                .reqwest_client
+                .post(SET_AVATAR_TYPE_API)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn redraw_avatar_thumbnail_internal(&self) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .post(REDRAW_THUMBNAIL_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn create_outfit_internal(
+            &self,
+            payload: OutfitPayload,
+        ) -> Result<u64, RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let body = request_types::OutfitReqBody::from(payload);
+
+            let request_result = self
+                .reqwest_client
+                .post(CREATE_OUTFIT_API)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::CreateOutfitResponse>(response).await?;
+
+            Ok(raw.id)
+        }
+
+        pub(super) async fn update_outfit_internal(
+            &self,
+            outfit_id: u64,
+            payload: OutfitPayload,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let formatted_url = UPDATE_OUTFIT_API.replace("{outfit_id}", &outfit_id.to_string());
+
+            let body = request_types::OutfitReqBody::from(payload);
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn rename_outfit_internal(
+            &self,
+            outfit_id: u64,
+            name: String,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let formatted_url = UPDATE_OUTFIT_API.replace("{outfit_id}", &outfit_id.to_string());
+
+            let body = request_types::RenameOutfitReqBody { name };
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn delete_outfit_internal(
+            &self,
+            outfit_id: u64,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let formatted_url = DELETE_OUTFIT_API.replace("{outfit_id}", &outfit_id.to_string());
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use super::{AvatarScales, AvatarType, BodyColors};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AvatarResponse {
+    pub scales: AvatarScales,
+    pub player_avatar_type: AvatarType,
+    pub body_color3s: BodyColors,
+    pub assets: Vec<AssetRaw>,
+    pub default_shirt_applied: bool,
+    pub default_pants_applied: bool,
+    pub emotes: Vec<EmoteRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AssetRaw {
+    pub id: u64,
+    pub name: String,
+    pub asset_type: AssetTypeRaw,
+    pub current_version_id: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AssetTypeRaw {
+    pub id: u64,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct EmoteRaw {
+    pub asset_id: u64,
+    pub asset_name: String,
+    pub position: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SetWearingAssetsReqBody {
+    pub assets: Vec<WearingAssetReq>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct WearingAssetReq {
+    pub id: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SetWearingAssetsResponse {
+    #[serde(default)]
+    pub invalid_asset_ids: Vec<u64>,
+    pub success: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SuccessResponse {
+    pub success: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SetPlayerAvatarTypeReqBody {
+    pub player_avatar_type: AvatarType,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OutfitsResponse {
+    pub data: Vec<OutfitRaw>,
+    pub pagination_token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OutfitRaw {
+    pub id: u64,
+    pub name: String,
+    pub is_editable: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OutfitDetailsResponse {
+    pub id: u64,
+    pub name: String,
+    pub assets: Vec<AssetRaw>,
+    pub body_color3s: BodyColors,
+    pub scale: AvatarScales,
+    pub player_avatar_type: AvatarType,
+    pub is_editable: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OutfitReqBody {
+    pub name: String,
+    pub body_color3s: BodyColors,
+    pub assets: Vec<WearingAssetReq>,
+    pub scale: AvatarScales,
+    pub player_avatar_type: AvatarType,
+    pub outfit_type: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RenameOutfitReqBody {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateOutfitResponse {
+    pub id: u64,
+}
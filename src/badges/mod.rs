@@ -0,0 +1,334 @@
+use crate::{Client, Limit, RoboatError};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const BADGE_DETAILS_API: &str = "https://badges.roblox.com/v1/badges/{badge_id}";
+const UNIVERSE_BADGES_API: &str = "https://badges.roblox.com/v1/universes/{universe_id}/badges?sortOrder={sort_order}&limit={limit}&cursor={cursor}";
+const USER_BADGES_API: &str = "https://badges.roblox.com/v1/users/{user_id}/badges?sortOrder={sort_order}&limit={limit}&cursor={cursor}";
+const AWARDED_DATES_API: &str =
+    "https://badges.roblox.com/v1/users/{user_id}/badges/awarded-dates?badgeIds={badge_ids}";
+
+/// The maximum amount of badge ids that can be requested at once from
+/// <https://badges.roblox.com/v1/users/{user_id}/badges/awarded-dates>.
+const AWARDED_DATES_CHUNK_SIZE: usize = 100;
+
+/// Universe badges are returned in the order they were created.
+const UNIVERSE_BADGES_SORT_ORDER: &str = "Asc";
+
+/// User badges are returned newest first, as the most recently awarded badges are usually
+/// the ones of interest.
+const USER_BADGES_SORT_ORDER: &str = "Desc";
+
+/// The universe a badge is awarded in.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct AwardingUniverse {
+    /// The id of the universe.
+    pub universe_id: u64,
+    /// The name of the universe.
+    pub name: String,
+    /// The id of the starting place of the universe.
+    pub root_place_id: u64,
+}
+
+/// The details of a badge. Retrieved using [`Client::badge_details`], [`Client::universe_badges`],
+/// and [`Client::user_badges`].
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct Badge {
+    /// The id of the badge.
+    pub badge_id: u64,
+    /// The name of the badge.
+    pub name: String,
+    /// The description of the badge.
+    pub description: String,
+    /// Whether the badge can currently be awarded.
+    pub enabled: bool,
+    /// The image id of the badge icon. Use [`ThumbnailType::BadgeIcon`](crate::thumbnails::ThumbnailType::BadgeIcon)
+    /// with the badge id to fetch a thumbnail url instead.
+    pub icon_image_id: u64,
+    /// When the badge was created, as an ISO 8601 timestamp.
+    pub created: String,
+    /// When the badge was last updated, as an ISO 8601 timestamp.
+    pub updated: String,
+    /// The amount of times the badge has been awarded.
+    pub awarded_count: u64,
+    /// The amount of times the badge has been awarded in the past day.
+    pub past_day_awarded_count: u64,
+    /// The percentage of players of the universe that have the badge.
+    pub win_rate_percentage: f64,
+    /// The universe the badge is awarded in.
+    pub awarding_universe: Option<AwardingUniverse>,
+}
+
+/// When a user was awarded a badge. Retrieved using [`Client::badge_awarded_dates`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct BadgeAwardedDate {
+    /// The id of the badge.
+    pub badge_id: u64,
+    /// When the badge was awarded, as an ISO 8601 timestamp.
+    pub awarded_date: String,
+}
+
+impl From<request_types::BadgeRaw> for Badge {
+    fn from(raw: request_types::BadgeRaw) -> Self {
+        let statistics = raw.statistics.unwrap_or(request_types::BadgeStatisticsRaw {
+            past_day_awarded_count: 0,
+            awarded_count: 0,
+            win_rate_percentage: 0.0,
+        });
+
+        let awarding_universe = raw.awarding_universe.map(|universe| AwardingUniverse {
+            universe_id: universe.id,
+            name: universe.name,
+            root_place_id: universe.root_place_id,
+        });
+
+        Self {
+            badge_id: raw.id,
+            name: raw.name,
+            description: raw.description.unwrap_or_default(),
+            enabled: raw.enabled,
+            icon_image_id: raw.icon_image_id,
+            created: raw.created,
+            updated: raw.updated,
+            awarded_count: statistics.awarded_count,
+            past_day_awarded_count: statistics.past_day_awarded_count,
+            win_rate_percentage: statistics.win_rate_percentage,
+            awarding_universe,
+        }
+    }
+}
+
+impl Client {
+    /// Fetches the details of a badge using <https://badges.roblox.com/v1/badges/{badge_id}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const BADGE_ID: u64 = 2124533401;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let badge = client.badge_details(BADGE_ID).await?;
+    ///
+    /// println!("Badge {} has been awarded {} times.", badge.name, badge.awarded_count);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn badge_details(&self, badge_id: u64) -> Result<Badge, RoboatError> {
+        let formatted_url = BADGE_DETAILS_API.replace("{badge_id}", &badge_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::BadgeRaw>(response).await?;
+
+        Ok(Badge::from(raw))
+    }
+
+    /// Fetches a page of the badges of a universe using
+    /// <https://badges.roblox.com/v1/universes/{universe_id}/badges>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of badges.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const UNIVERSE_ID: u64 = 2045364475;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let (badges, next_cursor) = client
+    ///     .universe_badges(UNIVERSE_ID, Limit::Hundred, None)
+    ///     .await?;
+    ///
+    /// for badge in badges {
+    ///     println!("Badge: {} / ID: {}", badge.name, badge.badge_id);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn universe_badges(
+        &self,
+        universe_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Badge>, Option<String>), RoboatError> {
+        let formatted_url = UNIVERSE_BADGES_API
+            .replace("{universe_id}", &universe_id.to_string())
+            .replace("{sort_order}", UNIVERSE_BADGES_SORT_ORDER)
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::BadgesResponse>(response).await?;
+
+        let badges = raw.data.into_iter().map(Badge::from).collect();
+
+        Ok((badges, raw.next_page_cursor))
+    }
+
+    /// Fetches a page of the badges a user has been awarded using
+    /// <https://badges.roblox.com/v1/users/{user_id}/badges>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Badges are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of badges.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (badges, next_cursor) = client.user_badges(USER_ID, Limit::Hundred, cursor).await?;
+    ///
+    ///     for badge in badges {
+    ///         println!("Badge: {} / ID: {}", badge.name, badge.badge_id);
+    ///     }
+    ///
+    ///     cursor = next_cursor;
+    ///
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_badges(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Badge>, Option<String>), RoboatError> {
+        let formatted_url = USER_BADGES_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{sort_order}", USER_BADGES_SORT_ORDER)
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::BadgesResponse>(response).await?;
+
+        let badges = raw.data.into_iter().map(Badge::from).collect();
+
+        Ok((badges, raw.next_page_cursor))
+    }
+
+    /// Fetches when a user was awarded each of the given badges using
+    /// <https://badges.roblox.com/v1/users/{user_id}/badges/awarded-dates>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Badge ids are requested in chunks of 100, so any amount of badge ids can be passed.
+    /// * Chunks are requested one after another to avoid hitting the rate limit.
+    ///
+    /// # Return Value Notes
+    /// * Only badges the user has been awarded are returned, so this can also be used
+    ///   to check badge ownership.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let badge_ids = vec![2124533401, 2124533402];
+    ///
+    /// let awarded_dates = client.badge_awarded_dates(USER_ID, badge_ids).await?;
+    ///
+    /// for awarded_date in awarded_dates {
+    ///     println!(
+    ///         "Badge {} awarded on {}",
+    ///         awarded_date.badge_id, awarded_date.awarded_date
+    ///     );
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn badge_awarded_dates(
+        &self,
+        user_id: u64,
+        badge_ids: Vec<u64>,
+    ) -> Result<Vec<BadgeAwardedDate>, RoboatError> {
+        let mut awarded_dates = Vec::new();
+
+        for chunk in badge_ids.chunks(AWARDED_DATES_CHUNK_SIZE) {
+            let badge_ids_str = chunk
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+
+            let formatted_url = AWARDED_DATES_API
+                .replace("{user_id}", &user_id.to_string())
+                .replace("{badge_ids}", &badge_ids_str);
+
+            let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::AwardedDatesResponse>(response).await?;
+
+            awarded_dates.extend(raw.data.into_iter().map(|x| BadgeAwardedDate {
+                badge_id: x.badge_id,
+                awarded_date: x.awarded_date,
+            }));
+        }
+
+        Ok(awarded_dates)
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BadgeRaw {
+    pub id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    pub enabled: bool,
+    pub icon_image_id: u64,
+    pub created: String,
+    pub updated: String,
+    pub statistics: Option<BadgeStatisticsRaw>,
+    pub awarding_universe: Option<AwardingUniverseRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BadgeStatisticsRaw {
+    pub past_day_awarded_count: u64,
+    pub awarded_count: u64,
+    pub win_rate_percentage: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwardingUniverseRaw {
+    pub id: u64,
+    pub name: String,
+    pub root_place_id: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BadgesResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<BadgeRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwardedDatesResponse {
+    pub data: Vec<AwardedDateRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AwardedDateRaw {
+    pub badge_id: u64,
+    pub awarded_date: String,
+}
@@ -1,6 +1,7 @@
 use crate::bedev2::request_types::AssetInfo;
 use crate::catalog::CreatorType;
-use crate::{Client, RoboatError};
+use crate::{Client, Limit, RoboatError, User};
+use reqwest::header;
 use serde::{Deserialize, Serialize};
 
 mod request_types;
@@ -18,6 +19,17 @@ const ASSET_API: &str = "https://apis.roblox.com/assets/user-auth/v1/assets";
 
 const PURCHASE_NON_TRADEABLE_LIMITED_API_PART_2: &str = "/purchase-item";
 
+const SESSIONS_API: &str = "https://apis.roblox.com/token-metadata-service/v1/sessions";
+const LOGOUT_SESSION_API: &str = "https://apis.roblox.com/token-metadata-service/v1/logout";
+
+const BLOCK_USER_API: &str =
+    "https://apis.roblox.com/user-blocking-api/v1/users/{user_id}/block-user";
+const UNBLOCK_USER_API: &str =
+    "https://apis.roblox.com/user-blocking-api/v1/users/{user_id}/unblock-user";
+const BLOCKED_USERS_API: &str = "https://apis.roblox.com/user-blocking-api/v1/users/get-blocked-users?count={limit}&cursor={cursor}";
+const IS_BLOCKED_API: &str =
+    "https://apis.roblox.com/user-blocking-api/v1/users/{user_id}/is-blocked";
+
 /// Custom Roblox errors that occur when using [`Client::purchase_non_tradable_limited`].
 #[non_exhaustive]
 #[derive(
@@ -107,6 +119,53 @@ pub struct NonTradableLimitedDetails {
     pub error_code: Option<u64>,
 }
 
+/// An active session of the authenticated account. Retrieved using [`Client::sessions`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Session {
+    /// The token used to identify (and revoke) the session. This is not a roblosecurity.
+    pub token: String,
+    /// Whether this is the session belonging to the roblosecurity in the client.
+    pub is_current_session: bool,
+    /// The type of device the session is on, such as "Browser" or "App".
+    pub device_type: Option<String>,
+    /// The browser or app the session is on, such as "Chrome".
+    pub device_name: Option<String>,
+    /// The operating system the session is on, such as "Windows".
+    pub os: Option<String>,
+    /// The city the session was last accessed from.
+    pub city: Option<String>,
+    /// The state or region the session was last accessed from.
+    pub subdivision: Option<String>,
+    /// The country the session was last accessed from.
+    pub country: Option<String>,
+    /// The ip the session was last accessed from.
+    pub last_accessed_ip: Option<String>,
+    /// When the session was last accessed, in milliseconds since the unix epoch.
+    pub last_accessed_timestamp_ms: Option<u64>,
+}
+
+impl From<request_types::SessionRaw> for Session {
+    fn from(raw: request_types::SessionRaw) -> Self {
+        let location = raw.location.unwrap_or_default();
+        let agent = raw.agent.unwrap_or_default();
+
+        Self {
+            token: raw.token,
+            is_current_session: raw.is_current_session,
+            device_type: agent.agent_type,
+            device_name: agent.value,
+            os: agent.os,
+            city: location.city,
+            subdivision: location.subdivision,
+            country: location.country,
+            last_accessed_ip: raw.last_accessed_ip,
+            last_accessed_timestamp_ms: raw
+                .last_accessed_timestamp_epoch_ms
+                .and_then(|x| x.parse().ok()),
+        }
+    }
+}
+
 impl TryFrom<request_types::NonTradableLimitedDetailsRaw> for NonTradableLimitedDetails {
     type Error = RoboatError;
 
@@ -155,7 +214,7 @@ impl Client {
     /// # Notes
     /// * Requires a valid roblosecurity.
     /// * The amount of items that can be requested at once is unknown as not enough non-tradable limiteds exist, and the
-    /// endpoint doesn't accept duplicates.
+    ///   endpoint doesn't accept duplicates.
     /// * Will repeat once if the x-csrf-token is invalid.
     ///
     /// # Errors
@@ -269,7 +328,7 @@ impl Client {
     /// # Notes
     /// * Requires a valid roblosecurity.
     /// * The amount of items that can be requested at once is unknown as not enough non-tradable limiteds exist, and the
-    /// endpoint doesn't accept duplicates.
+    ///   endpoint doesn't accept duplicates.
     /// * Will repeat once if the x-csrf-token is invalid.
     ///
     /// # Errors
@@ -385,20 +444,20 @@ impl Client {
     /// * Requires a valid roblosecurity.
     /// * Will repeat once if the x-csrf-token is invalid.
     /// * Currently only tested to work when buying from users (as opposed to groups), and only tested
-    /// when buying the items from the original seller (with original stock). This is because
-    /// these are the only conditions that currently exist as of 4/14/2023.
+    ///   when buying the items from the original seller (with original stock). This is because
+    ///   these are the only conditions that currently exist as of 4/14/2023.
     ///
     /// # Return Value Notes
     /// * Will return `Ok(())` if the limited was successfully purchased.
     ///
     /// # Argument Notes
     /// * `collectible_item_id` is the string id of a non-tradable limited. It can be
-    /// fetched using [`Client::collectible_item_id`].
+    ///   fetched using [`Client::collectible_item_id`].
     /// * `collectible_product_id` is the string product id of a non-tradable limited. It can be
-    /// fetched using [`Client::collectible_product_id`].
+    ///   fetched using [`Client::collectible_product_id`].
     /// * `collectible_seller_id` is the user id of the seller of a non-tradable limited. It can be
-    /// fetched using [`Client::collectible_creator_id`] (currently it is unknown how to buy from a reseller
-    /// instead of the original creator as they do not exist yet).
+    ///   fetched using [`Client::collectible_creator_id`] (currently it is unknown how to buy from a reseller
+    ///   instead of the original creator as they do not exist yet).
     ///
     /// # Errors
     /// * All errors under [Standard Errors](#standard-errors).
@@ -474,7 +533,7 @@ impl Client {
     /// # Notes
     /// * requires .ROBLOSECURITY cookie
     /// * Will repeat once if the x-csrf-token is invalid.
-
+    ///
     /// # Errors
     ///
     /// * All errors under [Standard Errors](#standard-errors).
@@ -502,7 +561,7 @@ impl Client {
     /// # }
     /// ```
     pub async fn get_asset_info(&self, asset_id: u64) -> Result<AssetInfo, RoboatError> {
-        match self.get_asset_info_internal(asset_id.clone()).await {
+        match self.get_asset_info_internal(asset_id).await {
             Ok(x) => Ok(x),
             Err(e) => match e {
                 RoboatError::InvalidXcsrf(new_xcsrf) => {
@@ -562,6 +621,325 @@ impl Client {
             },
         }
     }
+
+    /// Fetches a page of the authenticated account's active sessions using
+    /// <https://apis.roblox.com/token-metadata-service/v1/sessions>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of sessions.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (sessions, next_cursor) = client.sessions(None).await?;
+    ///
+    /// for session in sessions {
+    ///     println!(
+    ///         "{:?} on {:?} from {:?} (current: {})",
+    ///         session.device_name, session.os, session.country, session.is_current_session
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sessions(
+        &self,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Session>, Option<String>), RoboatError> {
+        let cookie_string = self.cookie_string()?;
+
+        let mut formatted_url = SESSIONS_API.to_string();
+
+        if let Some(cursor) = cursor {
+            formatted_url = format!("{}?nextCursor={}", formatted_url, cursor);
+        }
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::SessionsResponse>(response).await?;
+
+        let next_cursor = match raw.has_more {
+            true => raw.next_cursor.filter(|x| !x.is_empty()),
+            false => None,
+        };
+
+        let sessions = raw.sessions.into_iter().map(Session::from).collect();
+
+        Ok((sessions, next_cursor))
+    }
+
+    /// Revokes (logs out) a session of the authenticated account using
+    /// <https://apis.roblox.com/token-metadata-service/v1/logout>.
+    ///
+    /// To revoke every session except the current one, use [`Client::revoke_other_sessions`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * `token` is the [`Session::token`] of the session to revoke.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (sessions, _) = client.sessions(None).await?;
+    ///
+    /// for session in sessions {
+    ///     if !session.is_current_session {
+    ///         client.revoke_session(session.token).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn revoke_session(&self, token: String) -> Result<(), RoboatError> {
+        match self.revoke_session_internal(token.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.revoke_session_internal(token).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Blocks a user for the authenticated account using
+    /// <https://apis.roblox.com/user-blocking-api/v1/users/{user_id}/block-user>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Blocking a user also unfriends and unfollows them.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const USER_ID: u64 = 1;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.block_user(USER_ID).await?;
+    ///
+    /// println!("Blocked {}", USER_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn block_user(&self, user_id: u64) -> Result<(), RoboatError> {
+        match self.block_user_internal(user_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.block_user_internal(user_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Unblocks a user for the authenticated account using
+    /// <https://apis.roblox.com/user-blocking-api/v1/users/{user_id}/unblock-user>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const USER_ID: u64 = 1;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.unblock_user(USER_ID).await?;
+    ///
+    /// println!("Unblocked {}", USER_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unblock_user(&self, user_id: u64) -> Result<(), RoboatError> {
+        match self.unblock_user_internal(user_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.unblock_user_internal(user_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Fetches a page of the users blocked by the authenticated account using
+    /// <https://apis.roblox.com/user-blocking-api/v1/users/get-blocked-users>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of blocked users.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (blocked_users, next_cursor) = client.blocked_users(Limit::Fifty, None).await?;
+    ///
+    /// for user in blocked_users {
+    ///     println!("Blocked: {} ({})", user.username, user.user_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn blocked_users(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<User>, Option<String>), RoboatError> {
+        let cookie_string = self.cookie_string()?;
+
+        let formatted_url = BLOCKED_USERS_API
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::BlockedUsersResponse>(response).await?;
+
+        let blocked_users = raw
+            .data
+            .blocked_users
+            .into_iter()
+            .map(|user| User {
+                user_id: user.blocked_user_id,
+                username: user.name,
+                display_name: user.display_name,
+            })
+            .collect();
+
+        let next_cursor = raw.data.cursor.filter(|x| !x.is_empty());
+
+        Ok((blocked_users, next_cursor))
+    }
+
+    /// Checks whether the authenticated account has blocked a user using
+    /// <https://apis.roblox.com/user-blocking-api/v1/users/{user_id}/is-blocked>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const USER_ID: u64 = 1;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let is_blocked = client.is_blocked(USER_ID).await?;
+    ///
+    /// println!("Blocked {}: {}", USER_ID, is_blocked);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_blocked(&self, user_id: u64) -> Result<bool, RoboatError> {
+        let cookie_string = self.cookie_string()?;
+
+        let formatted_url = IS_BLOCKED_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let is_blocked = Self::parse_to_raw::<bool>(response).await?;
+
+        Ok(is_blocked)
+    }
 }
 
 mod internal {
@@ -572,7 +950,8 @@ mod internal {
     use super::{
         request_types, sort_items_by_argument_order, ClassicClothingType,
         NonTradableLimitedDetails, PurchaseNonTradableLimitedError, COLLECTIBLE_ITEM_DETAILS_API,
-        PURCHASE_NON_TRADEABLE_LIMITED_API_PART_1, PURCHASE_NON_TRADEABLE_LIMITED_API_PART_2,
+        LOGOUT_SESSION_API, PURCHASE_NON_TRADEABLE_LIMITED_API_PART_1,
+        PURCHASE_NON_TRADEABLE_LIMITED_API_PART_2,
     };
     use crate::{
         bedev2::{request_types::AssetInfo, ASSET_API},
@@ -743,6 +1122,66 @@ mod internal {
 
             Ok(())
         }
+
+        pub(super) async fn revoke_session_internal(
+            &self,
+            token: String,
+        ) -> Result<(), RoboatError> {
+            let request_body = serde_json::json!({
+                "token": token,
+            });
+
+            let request_result = self
+                .reqwest_client
+                .post(LOGOUT_SESSION_API)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .header(header::COOKIE, self.cookie_string()?)
+                .json(&request_body)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn block_user_internal(&self, user_id: u64) -> Result<(), RoboatError> {
+            let formatted_url = super::BLOCK_USER_API.replace("{user_id}", &user_id.to_string());
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .header(header::COOKIE, self.cookie_string()?)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn unblock_user_internal(&self, user_id: u64) -> Result<(), RoboatError> {
+            let formatted_url = super::UNBLOCK_USER_API.replace("{user_id}", &user_id.to_string());
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .header(header::COOKIE, self.cookie_string()?)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
     }
 }
 
@@ -74,3 +74,64 @@ pub struct Creator {
 pub struct ModerationResult {
     pub moderation_state: String,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SessionsResponse {
+    pub sessions: Vec<SessionRaw>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SessionRaw {
+    pub token: String,
+    pub location: Option<SessionLocationRaw>,
+    pub agent: Option<SessionAgentRaw>,
+    pub last_accessed_ip: Option<String>,
+    /// A stringified integer.
+    pub last_accessed_timestamp_epoch_ms: Option<String>,
+    pub is_current_session: bool,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SessionLocationRaw {
+    pub city: Option<String>,
+    pub subdivision: Option<String>,
+    pub country: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SessionAgentRaw {
+    #[serde(rename = "type")]
+    pub agent_type: Option<String>,
+    pub value: Option<String>,
+    pub os: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BlockedUsersResponse {
+    pub data: BlockedUsersDataRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BlockedUsersDataRaw {
+    #[serde(default)]
+    pub blocked_users: Vec<BlockedUserRaw>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BlockedUserRaw {
+    pub blocked_user_id: u64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+}
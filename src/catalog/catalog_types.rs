@@ -555,74 +555,92 @@ pub struct Item {
     pub id: u64,
 }
 
+/// The id Roblox uses for each asset type. Shared by the conversions to and from `u64`
+/// so that they cannot drift apart.
+const ASSET_TYPE_IDS: &[(AssetType, u64)] = &[
+    (AssetType::Image, 1),
+    (AssetType::TShirt, 2),
+    (AssetType::Audio, 3),
+    (AssetType::Mesh, 4),
+    (AssetType::Lua, 5),
+    (AssetType::Hat, 8),
+    (AssetType::Place, 9),
+    (AssetType::Model, 10),
+    (AssetType::Shirt, 11),
+    (AssetType::Pants, 12),
+    (AssetType::Decal, 13),
+    (AssetType::Head, 17),
+    (AssetType::Face, 18),
+    (AssetType::Gear, 19),
+    (AssetType::Badge, 21),
+    (AssetType::Animation, 24),
+    (AssetType::Arms, 25),
+    (AssetType::Legs, 26),
+    (AssetType::Torso, 27),
+    (AssetType::RightArm, 28),
+    (AssetType::LeftArm, 29),
+    (AssetType::LeftLeg, 30),
+    (AssetType::RightLeg, 31),
+    (AssetType::Package, 32),
+    (AssetType::GamePass, 34),
+    (AssetType::Plugin, 38),
+    (AssetType::MeshPart, 40),
+    (AssetType::HairAccessory, 41),
+    (AssetType::FaceAccessory, 42),
+    (AssetType::NeckAccessory, 43),
+    (AssetType::ShoulderAccessory, 44),
+    (AssetType::FrontAccessory, 45),
+    (AssetType::BackAccessory, 46),
+    (AssetType::WaistAccessory, 47),
+    (AssetType::ClimbAnimation, 48),
+    (AssetType::DeathAnimation, 49),
+    (AssetType::FallAnimation, 50),
+    (AssetType::IdleAnimation, 51),
+    (AssetType::JumpAnimation, 52),
+    (AssetType::RunAnimation, 53),
+    (AssetType::SwimAnimation, 54),
+    (AssetType::WalkAnimation, 55),
+    (AssetType::PoseAnimation, 56),
+    (AssetType::EarAccessory, 57),
+    (AssetType::EyeAccessory, 58),
+    (AssetType::EmoteAnimation, 61),
+    (AssetType::Video, 62),
+    (AssetType::TShirtAccessory, 64),
+    (AssetType::ShirtAccessory, 65),
+    (AssetType::PantsAccessory, 66),
+    (AssetType::JacketAccessory, 67),
+    (AssetType::SweaterAccessory, 68),
+    (AssetType::ShortsAccessory, 69),
+    (AssetType::LeftShoeAccessory, 70),
+    (AssetType::RightShoeAccessory, 71),
+    (AssetType::DressSkirtAccessory, 72),
+    (AssetType::FontFamily, 73),
+    (AssetType::EyebrowAccessory, 76),
+    (AssetType::EyelashAccessory, 77),
+    (AssetType::MoodAnimation, 78),
+    (AssetType::DynamicHead, 79),
+];
+
 impl TryFrom<u64> for AssetType {
     type Error = RoboatError;
 
     fn try_from(value: u64) -> Result<Self, Self::Error> {
-        match value {
-            1 => Ok(AssetType::Image),
-            2 => Ok(AssetType::TShirt),
-            3 => Ok(AssetType::Audio),
-            4 => Ok(AssetType::Mesh),
-            5 => Ok(AssetType::Lua),
-            8 => Ok(AssetType::Hat),
-            9 => Ok(AssetType::Place),
-            10 => Ok(AssetType::Model),
-            11 => Ok(AssetType::Shirt),
-            12 => Ok(AssetType::Pants),
-            13 => Ok(AssetType::Decal),
-            17 => Ok(AssetType::Head),
-            18 => Ok(AssetType::Face),
-            19 => Ok(AssetType::Gear),
-            21 => Ok(AssetType::Badge),
-            24 => Ok(AssetType::Animation),
-            25 => Ok(AssetType::Arms),
-            26 => Ok(AssetType::Legs),
-            27 => Ok(AssetType::Torso),
-            28 => Ok(AssetType::RightArm),
-            29 => Ok(AssetType::LeftArm),
-            30 => Ok(AssetType::LeftLeg),
-            31 => Ok(AssetType::RightLeg),
-            32 => Ok(AssetType::Package),
-            34 => Ok(AssetType::GamePass),
-            38 => Ok(AssetType::Plugin),
-            40 => Ok(AssetType::MeshPart),
-            41 => Ok(AssetType::HairAccessory),
-            42 => Ok(AssetType::FaceAccessory),
-            43 => Ok(AssetType::NeckAccessory),
-            44 => Ok(AssetType::ShoulderAccessory),
-            45 => Ok(AssetType::FrontAccessory),
-            46 => Ok(AssetType::BackAccessory),
-            47 => Ok(AssetType::WaistAccessory),
-            48 => Ok(AssetType::ClimbAnimation),
-            49 => Ok(AssetType::DeathAnimation),
-            50 => Ok(AssetType::FallAnimation),
-            51 => Ok(AssetType::IdleAnimation),
-            52 => Ok(AssetType::JumpAnimation),
-            53 => Ok(AssetType::RunAnimation),
-            54 => Ok(AssetType::SwimAnimation),
-            55 => Ok(AssetType::WalkAnimation),
-            56 => Ok(AssetType::PoseAnimation),
-            57 => Ok(AssetType::EarAccessory),
-            58 => Ok(AssetType::EyeAccessory),
-            61 => Ok(AssetType::EmoteAnimation),
-            62 => Ok(AssetType::Video),
-            64 => Ok(AssetType::TShirtAccessory),
-            65 => Ok(AssetType::ShirtAccessory),
-            66 => Ok(AssetType::PantsAccessory),
-            67 => Ok(AssetType::JacketAccessory),
-            68 => Ok(AssetType::SweaterAccessory),
-            69 => Ok(AssetType::ShortsAccessory),
-            70 => Ok(AssetType::LeftShoeAccessory),
-            71 => Ok(AssetType::RightShoeAccessory),
-            72 => Ok(AssetType::DressSkirtAccessory),
-            73 => Ok(AssetType::FontFamily),
-            76 => Ok(AssetType::EyebrowAccessory),
-            77 => Ok(AssetType::EyelashAccessory),
-            78 => Ok(AssetType::MoodAnimation),
-            79 => Ok(AssetType::DynamicHead),
-            _ => Err(RoboatError::MalformedResponse),
-        }
+        ASSET_TYPE_IDS
+            .iter()
+            .find(|(_, id)| *id == value)
+            .map(|(asset_type, _)| *asset_type)
+            .ok_or(RoboatError::MalformedResponse)
+    }
+}
+
+impl From<AssetType> for u64 {
+    fn from(value: AssetType) -> Self {
+        // Every variant has an id in the table.
+        ASSET_TYPE_IDS
+            .iter()
+            .find(|(asset_type, _)| *asset_type == value)
+            .map(|(_, id)| *id)
+            .unwrap_or_default()
     }
 }
 
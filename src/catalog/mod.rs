@@ -37,8 +37,8 @@ impl Client {
     ///
     /// # Argument Notes
     /// * The `id` parameter is that acts differently for this endpoint than others.
-    /// If the `item_type` is [`ItemType::Asset`], then `id` is the item ID.
-    /// Otherwise, if the `item_type` is [`ItemType::Bundle`], then `id` is the bundle ID.
+    ///   If the `item_type` is [`ItemType::Asset`], then `id` is the item ID.
+    ///   Otherwise, if the `item_type` is [`ItemType::Bundle`], then `id` is the bundle ID.
     ///
     /// # Errors
     /// * All errors under [Standard Errors](#standard-errors).
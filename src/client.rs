@@ -1,13 +1,16 @@
+use crate::groups::Role;
 use crate::users::ClientUserInformation;
 use crate::RoboatError;
 use reqwest::header::HeaderValue;
+use std::collections::HashMap;
 // We use tokio's version of rwlock so that readers to not starve writers on linux.
 use tokio::sync::RwLock;
 
 /// A client used for making requests to the Roblox API.
 ///
 /// The client stores the roblosecurity cookie, X-CSRF-TOKEN header, and an HTTPS client to send web
-/// requests. The client also caches the user id, username, and display name of the user.
+/// requests. The client also caches the user id, username, and display name of the user, as well
+/// as the roles of groups it has ranked members in.
 ///
 /// Constructed using a [`ClientBuilder`].
 ///
@@ -73,13 +76,17 @@ use tokio::sync::RwLock;
 #[derive(Debug, Default)]
 pub struct Client {
     /// The full cookie that includes the roblosecurity token.
-    pub(crate) cookie_string: Option<HeaderValue>,
+    ///
+    /// This uses std's rwlock as it is only held briefly and must be readable outside of async code.
+    pub(crate) cookie_string: std::sync::RwLock<Option<HeaderValue>>,
     /// The field holding the value for the X-CSRF-TOKEN header used in and returned by endpoints.
     pub(crate) xcsrf: RwLock<String>,
     /// Holds the user id, username, and display name of the user.
     pub(crate) user_information: RwLock<Option<ClientUserInformation>>,
     /// A Reqwest HTTP client used to send web requests.
     pub(crate) reqwest_client: reqwest::Client,
+    /// Holds the roles of groups by group id. Used to resolve ranks into role ids.
+    pub(crate) group_roles: RwLock<HashMap<u64, Vec<Role>>>,
 }
 
 /// A builder used for constructing a [`Client`]. Constructed using [`ClientBuilder::new`].
@@ -154,6 +161,29 @@ impl Client {
         *self.user_information.write().await = Some(user_information);
     }
 
+    /// Updates the cached display name of the user, if the user information has been cached.
+    /// Used after the display name is changed through [`Client::set_display_name`].
+    pub(crate) async fn set_cached_display_name(&self, display_name: String) {
+        if let Some(user_information) = &mut *self.user_information.write().await {
+            user_information.display_name = display_name;
+        }
+    }
+
+    /// Returns a copy of the cached roles of a group, if they have been cached.
+    pub(crate) async fn cached_group_roles(&self, group_id: u64) -> Option<Vec<Role>> {
+        self.group_roles.read().await.get(&group_id).cloned()
+    }
+
+    /// Caches the roles of a group. Used after the roles are fetched through [`Client::group_roles`].
+    pub(crate) async fn set_cached_group_roles(&self, group_id: u64, roles: Vec<Role>) {
+        self.group_roles.write().await.insert(group_id, roles);
+    }
+
+    /// Removes the cached roles of a group. Used after a role of the group is created, updated, or deleted.
+    pub(crate) async fn remove_cached_group_roles(&self, group_id: u64) {
+        self.group_roles.write().await.remove(&group_id);
+    }
+
     /// Sets the xcsrf token of the client. Remember to .await this method.
     pub(crate) async fn set_xcsrf(&self, xcsrf: String) {
         *self.xcsrf.write().await = xcsrf;
@@ -164,12 +194,19 @@ impl Client {
         self.xcsrf.read().await.clone()
     }
 
+    /// Replaces the roblosecurity of the client. Used when Roblox issues a new
+    /// roblosecurity, such as in [`Client::revoke_other_sessions`].
+    pub(crate) fn set_roblosecurity(&self, roblosecurity: &str) {
+        *self.cookie_string.write().unwrap() = Some(create_cookie_string_header(roblosecurity));
+    }
+
     /// Returns a copy of the cookie string stored in the client.
     /// If the roblosecurity has not been set, [`RoboatError::RoblosecurityNotSet`] is returned.
     pub(crate) fn cookie_string(&self) -> Result<HeaderValue, RoboatError> {
-        let cookie_string_opt = &self.cookie_string;
+        // The lock can only be poisoned if a writer panics, which setting an option cannot do.
+        let cookie_string_opt = self.cookie_string.read().unwrap();
 
-        match cookie_string_opt {
+        match &*cookie_string_opt {
             Some(cookie) => Ok(cookie.clone()),
             None => Err(RoboatError::RoblosecurityNotSet),
         }
@@ -221,10 +258,11 @@ impl ClientBuilder {
     /// ```
     pub fn build(self) -> Client {
         Client {
-            cookie_string: self
-                .roblosecurity
-                .as_ref()
-                .map(|x| create_cookie_string_header(x)),
+            cookie_string: std::sync::RwLock::new(
+                self.roblosecurity
+                    .as_ref()
+                    .map(|x| create_cookie_string_header(x)),
+            ),
             reqwest_client: self.reqwest_client.unwrap_or_default(),
             ..Default::default()
         }
@@ -7,6 +7,9 @@ mod request_types;
 const ROBUX_API_PART_1: &str = "https://economy.roblox.com/v1/users/";
 const ROBUX_API_PART_2: &str = "/currency";
 
+const GROUP_FUNDS_API_PART_1: &str = "https://economy.roblox.com/v1/groups/";
+const GROUP_FUNDS_API_PART_2: &str = "/currency";
+
 const RESELLERS_API_PART_1: &str = "https://economy.roblox.com/v1/assets/";
 const RESELLERS_API_PART_2: &str = "/resellers";
 
@@ -153,6 +156,54 @@ impl Client {
         Ok(robux)
     }
 
+    /// Grabs the robux funds of a group from <https://economy.roblox.com/v1/groups/{group_id}/currency>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The authenticated user must have permission to view the group's funds.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let funds = client.group_funds(GROUP_ID).await?;
+    /// println!("Group Funds: {}", funds);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_funds(&self, group_id: u64) -> Result<u64, RoboatError> {
+        let formatted_url = format!(
+            "{}{}{}",
+            GROUP_FUNDS_API_PART_1, group_id, GROUP_FUNDS_API_PART_2
+        );
+        let cookie = self.cookie_string()?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::CurrencyResponse>(response).await?;
+
+        let robux = raw.robux;
+
+        Ok(robux)
+    }
+
     /// Grabs resellers of an item from <https://economy.roblox.com/v1/assets/{item_id}/resellers?cursor={cursor}&limit={limit}>.
     ///
     /// # Notes
@@ -456,7 +507,7 @@ impl Client {
     /// # Return Value Notes
     /// * Will return `Ok(())` if the limited was successfully purchased.
     /// * As it will repeat once if the x-csrf-token is invalid, you may want to manually refresh the x-csrf-token
-    /// on another thread by using [`Client::force_refresh_xcsrf`].
+    ///   on another thread by using [`Client::force_refresh_xcsrf`].
     ///
     /// # Argument Notes
     /// * `product_id` is the product id of the limited, NOT the item id.
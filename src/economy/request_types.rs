@@ -77,11 +77,6 @@ pub(super) enum CurrencyTypeRaw {
     Robux,
 }
 
-#[derive(Serialize, Deserialize)]
-pub(super) enum TransactionTypeRaw {
-    Sale,
-}
-
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PurchaseLimitedResponse {
@@ -1,6 +1,11 @@
-use crate::{Client, RoboatError};
+use crate::presence::PresenceType;
+use crate::{Client, Limit, RoboatError, User};
+use futures_util::future;
+use futures_util::stream::{self, StreamExt};
 use reqwest::header;
 use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::num::NonZeroUsize;
 mod request_types;
 
 const FRIENDS_LIST_API: &str = "https://friends.roblox.com/v1/users/{user_id}/friends";
@@ -16,6 +21,28 @@ const DECLINE_FRIEND_REQUEST_API: &str =
 const SEND_FRIEND_REQUEST_API: &str =
     "https://friends.roblox.com/v1/users/{target_id}/request-friendship";
 const UNFRIEND_API: &str = "https://friends.roblox.com/v1/users/{target_id}/unfriend";
+const DECLINE_ALL_FRIEND_REQUESTS_API: &str =
+    "https://friends.roblox.com/v1/user/friend-requests/decline-all";
+
+const FRIEND_COUNT_API: &str = "https://friends.roblox.com/v1/users/{user_id}/friends/count";
+const FRIENDS_PAGE_API: &str =
+    "https://friends.roblox.com/v1/users/{user_id}/friends/find?limit={limit}&cursor={cursor}";
+
+/// The most friends <https://friends.roblox.com/v1/users/{user_id}/friends/find> returns per page.
+const FRIENDS_PAGE_MAX_LIMIT: u64 = 50;
+
+/// The maximum amount of users the presence endpoint accepts at once.
+const PRESENCE_CHUNK_SIZE: usize = 50;
+
+const FOLLOWER_COUNT_API: &str = "https://friends.roblox.com/v1/users/{user_id}/followers/count";
+const FOLLOWING_COUNT_API: &str = "https://friends.roblox.com/v1/users/{user_id}/followings/count";
+const FOLLOWERS_API: &str = "https://friends.roblox.com/v1/users/{user_id}/followers?sortOrder={sort_order}&limit={limit}&cursor={cursor}";
+const FOLLOWINGS_API: &str = "https://friends.roblox.com/v1/users/{user_id}/followings?sortOrder={sort_order}&limit={limit}&cursor={cursor}";
+const FOLLOW_API: &str = "https://friends.roblox.com/v1/users/{target_id}/follow";
+const UNFOLLOW_API: &str = "https://friends.roblox.com/v1/users/{target_id}/unfollow";
+
+/// Followers and followings are returned newest first.
+const FOLLOWS_SORT_ORDER: &str = "Desc";
 
 /// Struct for friend requests
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -27,6 +54,37 @@ pub struct FriendRequestsResponse {
     pub data: Vec<request_types::RequestResponseData>,
 }
 
+/// A friend of the authenticated user that is currently online. Retrieved using [`Client::online_friends`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct OnlineFriend {
+    /// The friend.
+    pub user: User,
+    /// Whether the friend is online, in game, or in studio.
+    pub presence_type: PresenceType,
+    /// The place the friend is in. This is `None` if the place is not visible to the authenticated user.
+    pub place_id: Option<u64>,
+    /// The root place of the universe the friend is in.
+    pub root_place_id: Option<u64>,
+    /// The universe the friend is in.
+    pub universe_id: Option<u64>,
+    /// The id of the server the friend is in.
+    pub game_id: Option<String>,
+    /// A description of where the friend is, such as the name of the game.
+    pub last_location: Option<String>,
+}
+
+/// The outcome of [`Client::process_friend_requests`].
+#[derive(Debug, Default)]
+pub struct ProcessedFriendRequests {
+    /// The user ids whose requests were accepted.
+    pub accepted: Vec<u64>,
+    /// The user ids whose requests were declined.
+    pub declined: Vec<u64>,
+    /// The error that stopped processing. Requests not in either vector were left untouched,
+    /// apart from the one that caused the error.
+    pub error: Option<RoboatError>,
+}
+
 // Make iterators go into data
 impl IntoIterator for FriendRequestsResponse {
     type Item = request_types::RequestResponseData;
@@ -63,7 +121,7 @@ impl Client {
     /// println!("Found {} friends.", friends.len());
     ///
     /// for friend in friends {
-    ///     println!("{}: {}", friend.username, friend.user_id);
+    ///     println!("{}: {}", friend.name, friend.id);
     /// }
     ///
     /// # Ok(())
@@ -106,7 +164,7 @@ impl Client {
     /// let (friend_requests, next_cursor) = client.friend_requests(None).await?;
     ///
     /// for user in friend_requests {
-    ///     println!("{} from {}: {}", user.username, user.user_id);
+    ///     println!("Friend request from {}: {}", user.name, user.id);
     /// }
     ///
     /// # Ok(())
@@ -353,13 +411,637 @@ impl Client {
             },
         }
     }
+
+    /// Fetches the amount of followers a user has using
+    /// <https://friends.roblox.com/v1/users/{user_id}/followers/count>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let count = client.follower_count(USER_ID).await?;
+    ///
+    /// println!("User {} has {} followers.", USER_ID, count);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follower_count(&self, user_id: u64) -> Result<u64, RoboatError> {
+        let formatted_url = FOLLOWER_COUNT_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::CountResponse>(response).await?;
+
+        Ok(raw.count)
+    }
+
+    /// Fetches the amount of users a user is following using
+    /// <https://friends.roblox.com/v1/users/{user_id}/followings/count>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let count = client.following_count(USER_ID).await?;
+    ///
+    /// println!("User {} is following {} users.", USER_ID, count);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn following_count(&self, user_id: u64) -> Result<u64, RoboatError> {
+        let formatted_url = FOLLOWING_COUNT_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::CountResponse>(response).await?;
+
+        Ok(raw.count)
+    }
+
+    /// Fetches a page of the followers of a user using
+    /// <https://friends.roblox.com/v1/users/{user_id}/followers>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Followers are ordered newest to oldest.
+    /// * Roblox may leave the username and display name empty. Use [`Client::user_details_bulk`]
+    ///   to fill them in if needed.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of users.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (followers, next_cursor) = client.followers(USER_ID, Limit::Hundred, cursor).await?;
+    ///
+    ///     for follower in followers {
+    ///         println!("Follower: {}", follower.user_id);
+    ///     }
+    ///
+    ///     cursor = next_cursor;
+    ///
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn followers(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<User>, Option<String>), RoboatError> {
+        let formatted_url = FOLLOWERS_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{sort_order}", FOLLOWS_SORT_ORDER)
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        self.follows_page(formatted_url).await
+    }
+
+    /// Fetches a page of the users a user is following using
+    /// <https://friends.roblox.com/v1/users/{user_id}/followings>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Followings are ordered newest to oldest.
+    /// * Roblox may leave the username and display name empty. Use [`Client::user_details_bulk`]
+    ///   to fill them in if needed.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of users.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let (followings, _) = client.followings(USER_ID, Limit::Hundred, None).await?;
+    ///
+    /// for following in followings {
+    ///     println!("Following: {}", following.user_id);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn followings(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<User>, Option<String>), RoboatError> {
+        let formatted_url = FOLLOWINGS_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{sort_order}", FOLLOWS_SORT_ORDER)
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        self.follows_page(formatted_url).await
+    }
+
+    /// Follows a user using <https://friends.roblox.com/v1/users/{target_id}/follow>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const TARGET_ID: u64 = 1;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.follow(TARGET_ID).await?;
+    ///
+    /// println!("Followed {}", TARGET_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow(&self, target_id: u64) -> Result<(), RoboatError> {
+        match self.follow_internal(target_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.follow_internal(target_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Unfollows a user using <https://friends.roblox.com/v1/users/{target_id}/unfollow>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const TARGET_ID: u64 = 1;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.unfollow(TARGET_ID).await?;
+    ///
+    /// println!("Unfollowed {}", TARGET_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unfollow(&self, target_id: u64) -> Result<(), RoboatError> {
+        match self.unfollow_internal(target_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.unfollow_internal(target_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Fetches the amount of friends a user has using
+    /// <https://friends.roblox.com/v1/users/{user_id}/friends/count>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let count = client.friend_count(USER_ID).await?;
+    ///
+    /// println!("User {} has {} friends.", USER_ID, count);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn friend_count(&self, user_id: u64) -> Result<u64, RoboatError> {
+        let formatted_url = FRIEND_COUNT_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::CountResponse>(response).await?;
+
+        Ok(raw.count)
+    }
+
+    /// Fetches a page of the friend ids of a user using
+    /// <https://friends.roblox.com/v1/users/{user_id}/friends/find>.
+    ///
+    /// Unlike [`Client::friends_list`], this endpoint is paginated, so it works
+    /// for users with a large amount of friends.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Roblox only accepts a limit of up to 50 on this endpoint, so [`Limit::Hundred`] is treated as 50.
+    /// * Use [`Client::user_details_bulk`] to get the usernames of the returned ids.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of user ids.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (friend_ids, next_cursor) = client.friends_page(USER_ID, Limit::Fifty, cursor).await?;
+    ///
+    ///     for friend_id in friend_ids {
+    ///         println!("Friend: {}", friend_id);
+    ///     }
+    ///
+    ///     cursor = next_cursor;
+    ///
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn friends_page(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<u64>, Option<String>), RoboatError> {
+        let limit = limit.to_u64().min(FRIENDS_PAGE_MAX_LIMIT);
+
+        let formatted_url = FRIENDS_PAGE_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{limit}", &limit.to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::FriendsPageResponse>(response).await?;
+
+        let friend_ids = raw.page_items.into_iter().map(|x| x.id).collect();
+
+        let next_cursor = match raw.has_more {
+            true => raw.next_cursor.filter(|x| !x.is_empty()),
+            false => None,
+        };
+
+        Ok((friend_ids, next_cursor))
+    }
+
+    /// Fetches the friends of the authenticated user that are currently online, using
+    /// <https://friends.roblox.com/v1/users/{user_id}/friends> and <https://presence.roblox.com/v1/presence/users>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Presences are fetched in chunks of 50 friends.
+    /// * Friends that are offline or invisible are not returned.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let online_friends = client.online_friends().await?;
+    ///
+    /// for friend in online_friends {
+    ///     println!(
+    ///         "{} is {:?} ({:?})",
+    ///         friend.user.username, friend.presence_type, friend.last_location
+    ///     );
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn online_friends(&self) -> Result<Vec<OnlineFriend>, RoboatError> {
+        let user_id = self.user_id().await?;
+        let friends = self.friends_list(user_id).await?;
+
+        let mut online_friends = Vec::new();
+
+        for chunk in friends.chunks(PRESENCE_CHUNK_SIZE) {
+            let user_ids = chunk.iter().map(|friend| friend.id).collect();
+            let presences = self.fetch_users_presence(user_ids).await?;
+
+            for presence in presences.user_presences {
+                match presence.user_presence_type {
+                    PresenceType::Offline | PresenceType::Invisible => continue,
+                    _ => {}
+                }
+
+                let friend = match chunk.iter().find(|friend| friend.id == presence.user_id) {
+                    Some(friend) => friend,
+                    None => continue,
+                };
+
+                let user = User {
+                    user_id: friend.id,
+                    username: friend.name.clone(),
+                    display_name: friend.display_name.clone(),
+                };
+
+                online_friends.push(OnlineFriend {
+                    user,
+                    presence_type: presence.user_presence_type,
+                    place_id: presence.place_id,
+                    root_place_id: presence.root_place_id,
+                    universe_id: presence.universe_id,
+                    game_id: presence.game_id,
+                    last_location: presence.last_location,
+                });
+            }
+        }
+
+        Ok(online_friends)
+    }
+
+    /// Declines every pending friend request using
+    /// <https://friends.roblox.com/v1/user/friend-requests/decline-all>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.decline_all_friend_requests().await?;
+    ///
+    /// println!("Declined all friend requests!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn decline_all_friend_requests(&self) -> Result<(), RoboatError> {
+        match self.decline_all_friend_requests_internal().await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.decline_all_friend_requests_internal().await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Goes through every page of [`Client::friend_requests`], accepting the requests that
+    /// `filter` returns `true` for and declining the rest.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Every page of friend requests is fetched before any request is accepted or declined.
+    /// * At most `concurrency` requests are accepted or declined at the same time.
+    /// * Stops at the first error. Requests already being processed are allowed to finish,
+    ///   but no new ones are started.
+    ///
+    /// # Return Value Notes
+    /// * [`ProcessedFriendRequests::accepted`] and [`ProcessedFriendRequests::declined`] contain the
+    ///   user ids whose requests were processed, even if an error occurred partway through.
+    /// * [`ProcessedFriendRequests::error`] contains the error that stopped processing, if any.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * An error is only returned directly if fetching the friend requests fails, in which case
+    ///   no request has been accepted or declined.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let concurrency = NonZeroUsize::new(5).unwrap();
+    ///
+    /// // Only accept requests from users we share a friend with.
+    /// let processed = client
+    ///     .process_friend_requests(|request| !request.mutual_friends_list.is_empty(), concurrency)
+    ///     .await?;
+    ///
+    /// println!(
+    ///     "Accepted {} and declined {} requests.",
+    ///     processed.accepted.len(),
+    ///     processed.declined.len()
+    /// );
+    ///
+    /// if let Some(e) = processed.error {
+    ///     return Err(e.into());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn process_friend_requests<F>(
+        &self,
+        filter: F,
+        concurrency: NonZeroUsize,
+    ) -> Result<ProcessedFriendRequests, RoboatError>
+    where
+        F: Fn(&request_types::RequestResponseData) -> bool,
+    {
+        // Every page is collected before anything is processed, as accepting or declining
+        // requests changes the list the cursors point into.
+        let mut decisions = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let (page, next_cursor) = self.friend_requests(cursor).await?;
+
+            decisions.extend(
+                page.into_iter()
+                    .map(|request| (request.id, filter(&request))),
+            );
+
+            cursor = next_cursor;
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        let mut processed = ProcessedFriendRequests::default();
+
+        // Once an error is recorded, no new requests are taken, but the ones
+        // already in flight are allowed to finish so that they are reported.
+        let stopped = Cell::new(false);
+
+        let mut results = stream::iter(decisions)
+            .take_while(|_| future::ready(!stopped.get()))
+            .map(|(requester_id, accept)| async move {
+                let result = match accept {
+                    true => self.accept_friend_request(requester_id).await,
+                    false => self.decline_friend_request(requester_id).await,
+                };
+
+                (requester_id, accept, result)
+            })
+            .buffer_unordered(concurrency.get());
+
+        while let Some((requester_id, accept, result)) = results.next().await {
+            match result {
+                Ok(()) => match accept {
+                    true => processed.accepted.push(requester_id),
+                    false => processed.declined.push(requester_id),
+                },
+                Err(e) => {
+                    stopped.set(true);
+                    processed.error.get_or_insert(e);
+                }
+            }
+        }
+
+        Ok(processed)
+    }
 }
 
 mod internal {
     use reqwest::header;
     use serde_json::json;
 
-    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use super::request_types;
+    use crate::{Client, RoboatError, User, XCSRF_HEADER};
 
     impl Client {
         pub(super) async fn accept_friend_request_internal(
@@ -443,6 +1125,25 @@ mod internal {
             Ok(())
         }
 
+        pub(super) async fn decline_all_friend_requests_internal(&self) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(super::DECLINE_ALL_FRIEND_REQUESTS_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
         pub(super) async fn unfriend_internal(&self, target_id: u64) -> Result<(), RoboatError> {
             let formatted_url = super::UNFRIEND_API.replace("{target_id}", &target_id.to_string());
 
@@ -463,5 +1164,71 @@ mod internal {
 
             Ok(())
         }
+
+        /// Fetches and parses a page from either the followers or followings endpoint, as they
+        /// share a response.
+        pub(super) async fn follows_page(
+            &self,
+            formatted_url: String,
+        ) -> Result<(Vec<User>, Option<String>), RoboatError> {
+            let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::FollowsResponse>(response).await?;
+
+            let users = raw
+                .data
+                .into_iter()
+                .map(|user| User {
+                    user_id: user.id,
+                    username: user.name,
+                    display_name: user.display_name,
+                })
+                .collect();
+
+            Ok((users, raw.next_page_cursor))
+        }
+
+        pub(super) async fn follow_internal(&self, target_id: u64) -> Result<(), RoboatError> {
+            let formatted_url = super::FOLLOW_API.replace("{target_id}", &target_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn unfollow_internal(&self, target_id: u64) -> Result<(), RoboatError> {
+            let formatted_url = super::UNFOLLOW_API.replace("{target_id}", &target_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
     }
 }
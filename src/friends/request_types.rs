@@ -28,6 +28,50 @@ pub(super) struct PendingFriendRequestsResponse {
     pub count: u64,
 }
 
+// [Friends page structs]
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct FriendsPageResponse {
+    pub previous_cursor: Option<String>,
+    pub page_items: Vec<FriendsPageItemRaw>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendsPageItemRaw {
+    pub id: u64,
+}
+
+// [Follower structs]
+
+/// Shared by the follower, following, and friend count endpoints
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CountResponse {
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FollowsResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<FollowUserRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FollowUserRaw {
+    pub id: u64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+}
+
 // [Friend requests structs]
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -54,10 +98,11 @@ pub struct RequestInfo {
 }
 
 /// Represents the source of a friend request or friend connection
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum OriginSource {
     /// Source is unknown or not specified
+    #[default]
     #[serde(rename = "Unknown")]
     Unknown = 0,
 
@@ -98,12 +143,6 @@ pub enum OriginSource {
     FriendRecommendations = 9,
 }
 
-impl Default for OriginSource {
-    fn default() -> Self {
-        OriginSource::Unknown
-    }
-}
-
 // Optionally, implement From<u8> to convert from numeric values
 impl From<u8> for OriginSource {
     fn from(value: u8) -> Self {
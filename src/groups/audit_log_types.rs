@@ -0,0 +1,355 @@
+use super::request_types;
+use super::MemberRole;
+use crate::{RoboatError, User};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+// Allow unused imports so they can be linked to in the docs.
+#[allow(unused_imports)]
+use crate::Client;
+
+/// The type of an action recorded in a group's audit log. Used to filter
+/// [`Client::group_audit_log`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Copy)]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub enum AuditLogActionType {
+    DeletePost,
+    RemoveMember,
+    AcceptJoinRequest,
+    DeclineJoinRequest,
+    PostStatus,
+    ChangeRank,
+    BuyAd,
+    SendAllyRequest,
+    CreateEnemy,
+    AcceptAllyRequest,
+    DeclineAllyRequest,
+    DeleteAlly,
+    DeleteEnemy,
+    AddGroupPlace,
+    RemoveGroupPlace,
+    CreateItems,
+    ConfigureItems,
+    SpendGroupFunds,
+    ChangeOwner,
+    Delete,
+    AdjustCurrencyAmounts,
+    Abandon,
+    Claim,
+    Rename,
+    ChangeDescription,
+    CreateGroupAsset,
+    UpdateGroupAsset,
+    ConfigureGroupAsset,
+    RevertGroupAsset,
+    CreateGroupDeveloperProduct,
+    ConfigureGroupGame,
+    Lock,
+    Unlock,
+    CreateGamePass,
+    CreateBadge,
+    ConfigureBadge,
+    SavePlace,
+    PublishPlace,
+    UpdateRolesetRank,
+    UpdateRolesetData,
+}
+
+/// An action recorded in a group's audit log, along with its decoded description.
+///
+/// Actions without a decoded description, or whose description Roblox changed
+/// the shape of, are returned as [`AuditLogAction::Other`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum AuditLogAction {
+    /// A member's role was changed.
+    ChangeRank {
+        /// The id of the user whose role was changed.
+        target_id: u64,
+        /// The username of the user whose role was changed.
+        target_name: String,
+        /// The id of the role the user had before.
+        old_role_id: u64,
+        /// The name of the role the user had before.
+        old_role_name: String,
+        /// The id of the role the user has now.
+        new_role_id: u64,
+        /// The name of the role the user has now.
+        new_role_name: String,
+    },
+    /// A user's join request was accepted.
+    AcceptJoinRequest {
+        /// The id of the user that joined.
+        target_id: u64,
+        /// The username of the user that joined.
+        target_name: String,
+    },
+    /// A user's join request was declined.
+    DeclineJoinRequest {
+        /// The id of the user that was declined.
+        target_id: u64,
+        /// The username of the user that was declined.
+        target_name: String,
+    },
+    /// A member was removed from the group.
+    RemoveMember {
+        /// The id of the user that was removed.
+        target_id: u64,
+        /// The username of the user that was removed.
+        target_name: String,
+    },
+    /// A wall post was deleted.
+    DeletePost {
+        /// The id of the user that made the post.
+        target_id: u64,
+        /// The username of the user that made the post.
+        target_name: String,
+        /// The contents of the deleted post.
+        post_description: String,
+    },
+    /// A shout was posted.
+    PostStatus {
+        /// The contents of the shout.
+        text: String,
+    },
+    /// Group funds were spent.
+    SpendGroupFunds {
+        /// The amount that was spent.
+        amount: u64,
+        /// The currency that was spent. Robux is 1.
+        currency_type: u64,
+        /// What the funds were spent on.
+        item_description: String,
+    },
+    /// An item owned by the group was configured.
+    ConfigureItems {
+        /// The id of the item.
+        target_id: u64,
+        /// The name of the item.
+        target_name: String,
+    },
+    /// The group description was changed.
+    ChangeDescription {
+        /// The new description.
+        new_description: String,
+    },
+    /// The group was renamed.
+    Rename {
+        /// The new name.
+        new_name: String,
+    },
+    /// Any other action.
+    Other {
+        /// The type of the action. This is `None` if roboat does not know the action type.
+        action_type: Option<AuditLogActionType>,
+        /// The undecoded description of the action.
+        description: serde_json::Value,
+    },
+}
+
+/// An entry in a group's audit log. Retrieved using [`Client::group_audit_log`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    /// The user that performed the action. This is `None` if Roblox did not return the user,
+    /// such as when the account was deleted.
+    pub actor: Option<User>,
+    /// The role the user had when they performed the action. This is `None` if Roblox did
+    /// not return the role.
+    pub actor_role: Option<MemberRole>,
+    /// The action that was performed.
+    pub action: AuditLogAction,
+    /// When the action was performed, as an ISO 8601 timestamp.
+    pub created: String,
+}
+
+/// The value Roblox uses for each action type, both when filtering and when returning entries.
+const ACTION_TYPE_NAMES: &[(AuditLogActionType, &str)] = &[
+    (AuditLogActionType::DeletePost, "DeletePost"),
+    (AuditLogActionType::RemoveMember, "RemoveMember"),
+    (AuditLogActionType::AcceptJoinRequest, "AcceptJoinRequest"),
+    (AuditLogActionType::DeclineJoinRequest, "DeclineJoinRequest"),
+    (AuditLogActionType::PostStatus, "PostStatus"),
+    (AuditLogActionType::ChangeRank, "ChangeRank"),
+    (AuditLogActionType::BuyAd, "BuyAd"),
+    (AuditLogActionType::SendAllyRequest, "SendAllyRequest"),
+    (AuditLogActionType::CreateEnemy, "CreateEnemy"),
+    (AuditLogActionType::AcceptAllyRequest, "AcceptAllyRequest"),
+    (AuditLogActionType::DeclineAllyRequest, "DeclineAllyRequest"),
+    (AuditLogActionType::DeleteAlly, "DeleteAlly"),
+    (AuditLogActionType::DeleteEnemy, "DeleteEnemy"),
+    (AuditLogActionType::AddGroupPlace, "AddGroupPlace"),
+    (AuditLogActionType::RemoveGroupPlace, "RemoveGroupPlace"),
+    (AuditLogActionType::CreateItems, "CreateItems"),
+    (AuditLogActionType::ConfigureItems, "ConfigureItems"),
+    (AuditLogActionType::SpendGroupFunds, "SpendGroupFunds"),
+    (AuditLogActionType::ChangeOwner, "ChangeOwner"),
+    (AuditLogActionType::Delete, "Delete"),
+    (
+        AuditLogActionType::AdjustCurrencyAmounts,
+        "AdjustCurrencyAmounts",
+    ),
+    (AuditLogActionType::Abandon, "Abandon"),
+    (AuditLogActionType::Claim, "Claim"),
+    (AuditLogActionType::Rename, "Rename"),
+    (AuditLogActionType::ChangeDescription, "ChangeDescription"),
+    (AuditLogActionType::CreateGroupAsset, "CreateGroupAsset"),
+    (AuditLogActionType::UpdateGroupAsset, "UpdateGroupAsset"),
+    (
+        AuditLogActionType::ConfigureGroupAsset,
+        "ConfigureGroupAsset",
+    ),
+    (AuditLogActionType::RevertGroupAsset, "RevertGroupAsset"),
+    (
+        AuditLogActionType::CreateGroupDeveloperProduct,
+        "CreateGroupDeveloperProduct",
+    ),
+    (AuditLogActionType::ConfigureGroupGame, "ConfigureGroupGame"),
+    (AuditLogActionType::Lock, "Lock"),
+    (AuditLogActionType::Unlock, "Unlock"),
+    (AuditLogActionType::CreateGamePass, "CreateGamePass"),
+    (AuditLogActionType::CreateBadge, "CreateBadge"),
+    (AuditLogActionType::ConfigureBadge, "ConfigureBadge"),
+    (AuditLogActionType::SavePlace, "SavePlace"),
+    (AuditLogActionType::PublishPlace, "PublishPlace"),
+    (AuditLogActionType::UpdateRolesetRank, "UpdateRolesetRank"),
+    (AuditLogActionType::UpdateRolesetData, "UpdateRolesetData"),
+];
+
+impl std::str::FromStr for AuditLogActionType {
+    type Err = RoboatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ACTION_TYPE_NAMES
+            .iter()
+            .find(|(_, name)| *name == s)
+            .map(|(action_type, _)| *action_type)
+            .ok_or(RoboatError::MalformedResponse)
+    }
+}
+
+impl std::fmt::Display for AuditLogActionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Every variant has a name in the table.
+        let name = ACTION_TYPE_NAMES
+            .iter()
+            .find(|(action_type, _)| action_type == self)
+            .map(|(_, name)| *name)
+            .unwrap_or_default();
+
+        write!(f, "{}", name)
+    }
+}
+
+impl AuditLogAction {
+    /// Decodes the description of an action. The endpoint returns action types
+    /// with spaces (such as "Change Rank"), so they are removed before matching.
+    fn from_raw(action_type: &str, description: Option<serde_json::Value>) -> Self {
+        let action_type = action_type
+            .replace(' ', "")
+            .parse::<AuditLogActionType>()
+            .ok();
+        let description = description.unwrap_or_default();
+
+        let action =
+            match action_type {
+                Some(AuditLogActionType::ChangeRank) => decode::<
+                    request_types::ChangeRankDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::ChangeRank {
+                    target_id: raw.target_id,
+                    target_name: raw.target_name,
+                    old_role_id: raw.old_role_set_id,
+                    old_role_name: raw.old_role_set_name,
+                    new_role_id: raw.new_role_set_id,
+                    new_role_name: raw.new_role_set_name,
+                }),
+                Some(AuditLogActionType::AcceptJoinRequest) => decode::<
+                    request_types::TargetDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::AcceptJoinRequest {
+                    target_id: raw.target_id,
+                    target_name: raw.target_name,
+                }),
+                Some(AuditLogActionType::DeclineJoinRequest) => decode::<
+                    request_types::TargetDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::DeclineJoinRequest {
+                    target_id: raw.target_id,
+                    target_name: raw.target_name,
+                }),
+                Some(AuditLogActionType::RemoveMember) => decode::<
+                    request_types::TargetDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::RemoveMember {
+                    target_id: raw.target_id,
+                    target_name: raw.target_name,
+                }),
+                Some(AuditLogActionType::DeletePost) => decode::<
+                    request_types::DeletePostDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::DeletePost {
+                    target_id: raw.target_id,
+                    target_name: raw.target_name,
+                    post_description: raw.post_desc,
+                }),
+                Some(AuditLogActionType::PostStatus) => {
+                    decode::<request_types::PostStatusDescriptionRaw>(&description)
+                        .map(|raw| Self::PostStatus { text: raw.text })
+                }
+                Some(AuditLogActionType::SpendGroupFunds) => decode::<
+                    request_types::SpendGroupFundsDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::SpendGroupFunds {
+                    amount: raw.amount,
+                    currency_type: raw.currency_type,
+                    item_description: raw.item_description,
+                }),
+                Some(AuditLogActionType::ConfigureItems) => decode::<
+                    request_types::TargetDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::ConfigureItems {
+                    target_id: raw.target_id,
+                    target_name: raw.target_name,
+                }),
+                Some(AuditLogActionType::ChangeDescription) => decode::<
+                    request_types::ChangeDescriptionDescriptionRaw,
+                >(&description)
+                .map(|raw| Self::ChangeDescription {
+                    new_description: raw.new_description,
+                }),
+                Some(AuditLogActionType::Rename) => {
+                    decode::<request_types::RenameDescriptionRaw>(&description).map(|raw| {
+                        Self::Rename {
+                            new_name: raw.new_name,
+                        }
+                    })
+                }
+                _ => None,
+            };
+
+        action.unwrap_or(Self::Other {
+            action_type,
+            description,
+        })
+    }
+}
+
+impl From<request_types::AuditLogEntryRaw> for AuditLogEntry {
+    fn from(raw: request_types::AuditLogEntryRaw) -> Self {
+        let (actor, actor_role) = match raw.actor {
+            Some(actor) => (actor.user.map(User::from), actor.role.map(MemberRole::from)),
+            None => (None, None),
+        };
+
+        Self {
+            actor,
+            actor_role,
+            action: AuditLogAction::from_raw(&raw.action_type, raw.description),
+            created: raw.created,
+        }
+    }
+}
+
+fn decode<T: DeserializeOwned>(description: &serde_json::Value) -> Option<T> {
+    serde_json::from_value(description.clone()).ok()
+}
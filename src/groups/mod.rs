@@ -1,6 +1,13 @@
 use crate::{Client, Limit, RoboatError, User};
+use reqwest::header::{self, HeaderValue};
 use serde::{Deserialize, Serialize};
 
+// Re-export all types so that they are easily accessible from the crate root.
+pub use audit_log_types::{AuditLogAction, AuditLogActionType, AuditLogEntry};
+
+/// Types related to the group audit log.
+/// They are in this module because there are so many.
+pub mod audit_log_types;
 mod request_types;
 
 const GROUP_ROLES_API: &str = "https://groups.roblox.com/v1/groups/{group_id}/roles";
@@ -11,6 +18,178 @@ const GROUP_ROLE_MEMBERS_API: &str =
 
 const CHANGE_GROUP_MEMBER_ROLE_API: &str =
     "https://groups.roblox.com/v1/groups/{group_id}/users/{user_id}";
+const CHANGE_GROUP_MEMBER_ROLE_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (2, GroupError::RoleNotFound),
+    (3, GroupError::UserNotFound),
+    (4, GroupError::InsufficientPermissions),
+];
+
+const EXILE_MEMBER_API: &str = "https://groups.roblox.com/v1/groups/{group_id}/users/{user_id}";
+const EXILE_MEMBER_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (3, GroupError::UserNotFound),
+    (4, GroupError::InsufficientPermissions),
+];
+
+const CREATE_ROLE_API: &str = "https://groups.roblox.com/v1/groups/{group_id}/rolesets/create";
+const ROLE_API: &str = "https://groups.roblox.com/v1/groups/{group_id}/rolesets/{role_id}";
+const ROLE_PERMISSIONS_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/roles/{role_id}/permissions";
+const CREATE_ROLE_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (4, GroupError::InsufficientPermissions),
+];
+const ROLE_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (2, GroupError::RoleNotFound),
+    (4, GroupError::InsufficientPermissions),
+];
+const ROLE_PERMISSIONS_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (2, GroupError::RoleNotFound),
+    (3, GroupError::InsufficientPermissions),
+];
+
+const GROUP_DETAILS_API: &str = "https://groups.roblox.com/v1/groups/{group_id}";
+const GROUP_SHOUT_API: &str = "https://groups.roblox.com/v1/groups/{group_id}/status";
+const GROUP_DESCRIPTION_API: &str = "https://groups.roblox.com/v1/groups/{group_id}/description";
+const GROUP_SHOUT_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (5, GroupError::InsufficientPermissions),
+];
+const GROUP_DESCRIPTION_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (23, GroupError::InsufficientPermissions),
+];
+
+const USER_GROUPS_API: &str = "https://groups.roblox.com/v1/users/{user_id}/groups/roles";
+const PRIMARY_GROUP_API: &str = "https://groups.roblox.com/v1/users/{user_id}/groups/primary/role";
+
+const AUDIT_LOG_SORT_ORDER: &str = "Desc";
+const AUDIT_LOG_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/audit-log?cursor={cursor}&limit={limit}&sortOrder={sort_order}";
+const AUDIT_LOG_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (23, GroupError::InsufficientPermissions),
+];
+
+const WALL_POSTS_SORT_ORDER: &str = "Desc";
+const WALL_POSTS_API: &str =
+    "https://groups.roblox.com/v2/groups/{group_id}/wall/posts?cursor={cursor}&limit={limit}&sortOrder={sort_order}";
+const POST_TO_WALL_API: &str = "https://groups.roblox.com/v2/groups/{group_id}/wall/posts";
+const DELETE_WALL_POST_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/wall/posts/{post_id}";
+const DELETE_WALL_POSTS_BY_USER_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/wall/users/{user_id}/posts";
+const WALL_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (2, GroupError::InsufficientPermissions),
+];
+const DELETE_WALL_POSTS_BY_USER_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (2, GroupError::InsufficientPermissions),
+    (3, GroupError::UserNotFound),
+];
+
+const PAYOUTS_API: &str = "https://groups.roblox.com/v1/groups/{group_id}/payouts";
+const RECURRING_PAYOUT_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/payouts/recurring";
+
+const ONE_TIME_PAYOUT_TYPE: &str = "FixedAmount";
+const RECURRING_PAYOUT_TYPE: &str = "Percentage";
+const PAYOUT_RECIPIENT_TYPE: &str = "User";
+const PAYOUT_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (23, GroupError::InsufficientPermissions),
+];
+
+const JOIN_REQUESTS_SORT_ORDER: &str = "Asc";
+const JOIN_REQUESTS_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/join-requests?cursor={cursor}&limit={limit}&sortOrder={sort_order}";
+const JOIN_REQUESTS_BATCH_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/join-requests";
+const JOIN_REQUEST_API: &str =
+    "https://groups.roblox.com/v1/groups/{group_id}/join-requests/users/{user_id}";
+const JOIN_REQUEST_ERRORS: &[(u16, GroupError)] = &[
+    (1, GroupError::GroupNotFound),
+    (3, GroupError::UserNotFound),
+    (19, GroupError::InsufficientPermissions),
+    (20, GroupError::JoinRequestNotFound),
+];
+
+/// Custom Roblox errors that occur when managing a group.
+#[non_exhaustive]
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum GroupError {
+    /// Thrown when the authenticated user does not have the group permission needed
+    /// for the action. There is no point in retrying after this error.
+    #[default]
+    #[error("Insufficient Permissions")]
+    InsufficientPermissions,
+    /// Thrown when the group does not exist.
+    #[error("Group Not Found")]
+    GroupNotFound,
+    /// Thrown when the target user does not exist.
+    #[error("User Not Found")]
+    UserNotFound,
+    /// Thrown when the user does not have a pending join request for the group.
+    #[error("Join Request Not Found")]
+    JoinRequestNotFound,
+    /// Thrown when the role does not exist, when the group has no role with the requested
+    /// rank, or when there is no role above or below the member's current role.
+    #[error("Role Not Found")]
+    RoleNotFound,
+    /// Thrown when the target user is not a member of the group.
+    #[error("User Not In Group")]
+    UserNotInGroup,
+}
+
+/// Custom Roblox errors that occur when paying out group funds.
+#[non_exhaustive]
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum PayoutError {
+    /// Thrown when the group does not have enough robux for the payout.
+    #[default]
+    #[error("Insufficient Funds")]
+    InsufficientFunds,
+    /// Thrown when one of the recipients cannot receive payouts from the group,
+    /// such as when they are not a member or joined too recently.
+    #[error("Recipient Not Eligible")]
+    RecipientNotEligible,
+    /// Thrown when Roblox requires a challenge to be completed before paying out.
+    /// Contains the challenge id.
+    #[error("Challenge Required: {0}")]
+    ChallengeRequired(String),
+    /// Thrown when the percentages passed to [`Client::set_group_recurring_payouts`]
+    /// add up to more than 100. This is checked before any request is sent.
+    #[error("Invalid Percentages")]
+    InvalidPercentages,
+}
 
 /// A role in a group.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
@@ -27,6 +206,289 @@ pub struct Role {
     pub member_count: u64,
 }
 
+/// The role a member has in a group. Unlike [`Role`], it does not include the member count
+/// of the role, as Roblox does not provide it alongside a member.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct MemberRole {
+    /// The ID of the role.
+    pub id: u64,
+    /// The name of the role.
+    pub name: String,
+    /// A number from 0 to 255 that determines the role's rank, with
+    /// 255 being the highest rank and 0 being the lowest rank.
+    pub rank: u8,
+}
+
+/// The permissions of a group role. Retrieved using [`Client::group_role_permissions`].
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub struct RolePermissions {
+    pub view_wall: bool,
+    pub post_to_wall: bool,
+    pub delete_from_wall: bool,
+    pub view_status: bool,
+    pub post_to_status: bool,
+    pub change_rank: bool,
+    pub invite_members: bool,
+    pub remove_members: bool,
+    pub manage_relationships: bool,
+    pub view_audit_logs: bool,
+    pub spend_group_funds: bool,
+    pub advertise_group: bool,
+    pub create_items: bool,
+    pub manage_items: bool,
+    pub add_group_places: bool,
+    pub manage_group_games: bool,
+    pub view_group_payouts: bool,
+    pub view_analytics: bool,
+}
+
+/// The shout (status) posted on a group.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupShout {
+    /// The contents of the shout.
+    pub body: String,
+    /// The user that posted the shout.
+    pub poster: Option<User>,
+    /// When the shout was created, as an ISO 8601 timestamp.
+    pub created: String,
+    /// When the shout was last updated, as an ISO 8601 timestamp.
+    pub updated: String,
+}
+
+/// The details of a group. Retrieved using [`Client::group_details`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupDetails {
+    /// The id of the group.
+    pub group_id: u64,
+    /// The name of the group.
+    pub name: String,
+    /// The description of the group.
+    pub description: String,
+    /// The owner of the group. This is `None` if the group has no owner.
+    pub owner: Option<User>,
+    /// The current shout of the group. This is `None` if no shout is posted.
+    pub shout: Option<GroupShout>,
+    /// The amount of members in the group.
+    pub member_count: u64,
+    /// Whether users can join the group without sending a join request.
+    pub public_entry_allowed: bool,
+    /// Whether the group is locked by Roblox moderation.
+    pub is_locked: bool,
+    /// Whether the group has a verified badge.
+    pub has_verified_badge: bool,
+}
+
+/// A pending request to join a group. Retrieved using [`Client::group_join_requests`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct JoinRequest {
+    /// The user that wants to join the group.
+    pub requester: User,
+    /// When the request was sent, as an ISO 8601 timestamp.
+    pub created: String,
+}
+
+/// A group that a user is in, along with the user's role in it.
+/// Retrieved using [`Client::user_groups`] and [`Client::primary_group`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UserGroup {
+    /// The details of the group.
+    pub group: GroupDetails,
+    /// The role the user has in the group.
+    pub role: MemberRole,
+}
+
+/// A post on a group wall. Retrieved using [`Client::group_wall_posts`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct WallPost {
+    /// The id of the post.
+    pub post_id: u64,
+    /// The user that made the post. This is `None` if the poster's account was deleted.
+    pub poster: Option<User>,
+    /// The role the poster had in the group.
+    pub poster_role: Option<MemberRole>,
+    /// The contents of the post.
+    pub body: String,
+    /// When the post was created, as an ISO 8601 timestamp.
+    pub created: String,
+    /// When the post was last updated, as an ISO 8601 timestamp.
+    pub updated: String,
+}
+
+/// A user that receives a percentage of a group's revenue as a recurring payout.
+/// Retrieved using [`Client::group_payout_recipients`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PayoutRecipient {
+    /// The user receiving the payout.
+    pub user: User,
+    /// The percentage of the group's revenue the user receives.
+    pub percentage: u8,
+}
+
+/// A one-time payout of robux to a group member. Used in [`Client::group_payout`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub struct PayoutArgs {
+    /// The id of the user receiving the payout.
+    pub user_id: u64,
+    /// The amount of robux to pay out.
+    pub amount: u64,
+}
+
+/// A recurring payout of a percentage of a group's revenue to a group member.
+/// Used in [`Client::set_group_recurring_payouts`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub struct RecurringPayoutArgs {
+    /// The id of the user receiving the payout.
+    pub user_id: u64,
+    /// The percentage of the group's revenue the user receives.
+    pub percentage: u8,
+}
+
+impl From<request_types::GroupUserRaw> for User {
+    fn from(raw: request_types::GroupUserRaw) -> Self {
+        Self {
+            user_id: raw.user_id,
+            username: raw.username,
+            display_name: raw.display_name,
+        }
+    }
+}
+
+impl From<request_types::GroupDetailsResponse> for GroupDetails {
+    fn from(raw: request_types::GroupDetailsResponse) -> Self {
+        let shout = raw.shout.map(|shout| GroupShout {
+            body: shout.body,
+            poster: shout.poster.map(User::from),
+            created: shout.created,
+            updated: shout.updated,
+        });
+
+        Self {
+            group_id: raw.id,
+            name: raw.name,
+            description: raw.description,
+            owner: raw.owner.map(User::from),
+            shout,
+            member_count: raw.member_count,
+            public_entry_allowed: raw.public_entry_allowed,
+            is_locked: raw.is_locked,
+            has_verified_badge: raw.has_verified_badge,
+        }
+    }
+}
+
+impl From<request_types::RoleRaw> for Role {
+    fn from(raw: request_types::RoleRaw) -> Self {
+        Self {
+            id: raw.id,
+            name: raw.name,
+            rank: raw.rank,
+            member_count: raw.member_count.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<request_types::UserGroupRoleRaw> for MemberRole {
+    fn from(raw: request_types::UserGroupRoleRaw) -> Self {
+        Self {
+            id: raw.id,
+            name: raw.name,
+            rank: raw.rank,
+        }
+    }
+}
+
+impl From<request_types::UserGroupRaw> for UserGroup {
+    fn from(raw: request_types::UserGroupRaw) -> Self {
+        Self {
+            group: GroupDetails::from(raw.group),
+            role: MemberRole::from(raw.role),
+        }
+    }
+}
+
+impl From<request_types::RolePermissionsRaw> for RolePermissions {
+    fn from(raw: request_types::RolePermissionsRaw) -> Self {
+        let posts = raw.group_posts_permissions;
+        let membership = raw.group_membership_permissions;
+        let management = raw.group_management_permissions;
+        let economy = raw.group_economy_permissions;
+
+        Self {
+            view_wall: posts.view_wall,
+            post_to_wall: posts.post_to_wall,
+            delete_from_wall: posts.delete_from_wall,
+            view_status: posts.view_status,
+            post_to_status: posts.post_to_status,
+            change_rank: membership.change_rank,
+            invite_members: membership.invite_members,
+            remove_members: membership.remove_members,
+            manage_relationships: management.manage_relationships,
+            view_audit_logs: management.view_audit_logs,
+            spend_group_funds: economy.spend_group_funds,
+            advertise_group: economy.advertise_group,
+            create_items: economy.create_items,
+            manage_items: economy.manage_items,
+            add_group_places: economy.add_group_places,
+            manage_group_games: economy.manage_group_games,
+            view_group_payouts: economy.view_group_payouts,
+            view_analytics: economy.view_analytics,
+        }
+    }
+}
+
+impl From<RolePermissions> for request_types::RolePermissionsReq {
+    fn from(permissions: RolePermissions) -> Self {
+        Self {
+            view_wall: permissions.view_wall,
+            post_to_wall: permissions.post_to_wall,
+            delete_from_wall: permissions.delete_from_wall,
+            view_status: permissions.view_status,
+            post_to_status: permissions.post_to_status,
+            change_rank: permissions.change_rank,
+            invite_members: permissions.invite_members,
+            remove_members: permissions.remove_members,
+            manage_relationships: permissions.manage_relationships,
+            view_audit_logs: permissions.view_audit_logs,
+            spend_group_funds: permissions.spend_group_funds,
+            advertise_group: permissions.advertise_group,
+            create_items: permissions.create_items,
+            manage_items: permissions.manage_items,
+            add_group_places: permissions.add_group_places,
+            manage_group_games: permissions.manage_group_games,
+            view_group_payouts: permissions.view_group_payouts,
+            view_analytics: permissions.view_analytics,
+        }
+    }
+}
+
+impl From<request_types::WallPostRaw> for WallPost {
+    fn from(raw: request_types::WallPostRaw) -> Self {
+        let (poster, poster_role) = match raw.poster {
+            Some(poster) => (
+                Some(User::from(poster.user)),
+                Some(MemberRole::from(poster.role)),
+            ),
+            None => (None, None),
+        };
+
+        Self {
+            post_id: raw.id,
+            poster,
+            poster_role,
+            body: raw.body,
+            created: raw.created,
+            updated: raw.updated,
+        }
+    }
+}
+
 impl Client {
     /// Returns the roles of a group using <https://groups.roblox.com/v1/groups/{group_id}/roles>.
     ///
@@ -73,7 +535,7 @@ impl Client {
         let mut roles = raw.roles;
 
         // Enforce that the roles are in order by rank in ascending order
-        roles.sort_by(|a, b| a.rank.cmp(&b.rank));
+        roles.sort_by_key(|role| role.rank);
 
         Ok(roles)
     }
@@ -205,39 +667,2306 @@ impl Client {
             },
         }
     }
-}
 
-mod internal {
-    use super::CHANGE_GROUP_MEMBER_ROLE_API;
-    use crate::{Client, RoboatError, XCSRF_HEADER};
-    use reqwest::header;
+    /// Sets a group member's role by rank using <https://groups.roblox.com/v1/groups/{group_id}/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The roles of the group are fetched with [`Client::group_roles`] and cached in the client.
+    ///   The cache is refreshed if no role has the given rank.
+    ///
+    /// # Argument Notes
+    /// * `rank` is a number from 1 to 255, not a role id.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::RoleNotFound`], [`GroupError::UserNotFound`], and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const USER_ID: u64 = 123456789;
+    /// const GROUP_ID: u64 = 1127093;
+    /// const RANK: u8 = 10;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.set_group_member_rank(USER_ID, GROUP_ID, RANK).await?;
+    ///
+    /// println!("Set user {}'s rank to {} in group {}.", USER_ID, RANK, GROUP_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_group_member_rank(
+        &self,
+        user_id: u64,
+        group_id: u64,
+        rank: u8,
+    ) -> Result<(), RoboatError> {
+        let mut role = self
+            .group_roles_cached(group_id, false)
+            .await?
+            .into_iter()
+            .find(|role| role.rank == rank);
 
-    impl Client {
-        pub(super) async fn set_group_member_role_internal(
-            &self,
-            user_id: u64,
-            group_id: u64,
-            role_id: u64,
-        ) -> Result<(), RoboatError> {
-            let formatted_url = CHANGE_GROUP_MEMBER_ROLE_API
-                .replace("{group_id}", &group_id.to_string())
-                .replace("{user_id}", &user_id.to_string());
+        // The cached roles may be out of date, so they are fetched again before giving up.
+        if role.is_none() {
+            role = self
+                .group_roles_cached(group_id, true)
+                .await?
+                .into_iter()
+                .find(|role| role.rank == rank);
+        }
 
-            let cookie = self.cookie_string()?;
-            let xcsrf = self.xcsrf().await;
+        let role = role.ok_or(RoboatError::GroupError(GroupError::RoleNotFound))?;
 
-            let json = serde_json::json!({ "roleId": role_id });
+        self.set_group_member_role(user_id, group_id, role.id)
+            .await
+            .map_err(|e| group_error(e, CHANGE_GROUP_MEMBER_ROLE_ERRORS))
+    }
 
-            let request_result = self
-                .reqwest_client
-                .patch(formatted_url)
-                .json(&json)
-                .header(header::COOKIE, cookie)
-                .header(XCSRF_HEADER, xcsrf)
-                .send()
-                .await;
+    /// Moves a group member to the role directly above their current role.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The member's current role is found with [`Client::user_groups`].
+    /// * The roles of the group are fetched with [`Client::group_roles`] and cached in the client.
+    ///   The cache is refreshed if no role is above the member's current role.
+    /// * Members are never promoted to the owner role (rank 255).
+    ///
+    /// # Return Value Notes
+    /// * Returns the member's new role.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::RoleNotFound`], [`GroupError::UserNotFound`],
+    ///   [`GroupError::UserNotInGroup`], and [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const USER_ID: u64 = 123456789;
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let new_role = client.promote(USER_ID, GROUP_ID).await?;
+    ///
+    /// println!("Promoted user {} to {}.", USER_ID, new_role.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn promote(&self, user_id: u64, group_id: u64) -> Result<Role, RoboatError> {
+        self.shift_rank_internal(user_id, group_id, true).await
+    }
 
-            let _ = Self::validate_request_result(request_result).await?;
+    /// Moves a group member to the role directly below their current role.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The member's current role is found with [`Client::user_groups`].
+    /// * The roles of the group are fetched with [`Client::group_roles`] and cached in the client.
+    ///   The cache is refreshed if no role is below the member's current role.
+    /// * Members are never demoted to the guest role (rank 0).
+    ///
+    /// # Return Value Notes
+    /// * Returns the member's new role.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::RoleNotFound`], [`GroupError::UserNotFound`],
+    ///   [`GroupError::UserNotInGroup`], and [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const USER_ID: u64 = 123456789;
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let new_role = client.demote(USER_ID, GROUP_ID).await?;
+    ///
+    /// println!("Demoted user {} to {}.", USER_ID, new_role.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn demote(&self, user_id: u64, group_id: u64) -> Result<Role, RoboatError> {
+        self.shift_rank_internal(user_id, group_id, false).await
+    }
+
+    /// Removes a member from a group using <https://groups.roblox.com/v1/groups/{group_id}/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to remove members from the group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::UserNotFound`], and [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const USER_ID: u64 = 123456789;
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.exile_member(USER_ID, GROUP_ID).await?;
+    ///
+    /// println!("Exiled user {} from group {}.", USER_ID, GROUP_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exile_member(&self, user_id: u64, group_id: u64) -> Result<(), RoboatError> {
+        match self.exile_member_internal(user_id, group_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.exile_member_internal(user_id, group_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Makes the authenticated user leave a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The owner of a group cannot leave it.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.leave_group(GROUP_ID).await?;
+    ///
+    /// println!("Left group {}.", GROUP_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn leave_group(&self, group_id: u64) -> Result<(), RoboatError> {
+        let user_id = self.user_id().await?;
+
+        self.exile_member(user_id, group_id).await
+    }
+
+    /// Creates a role in a group using <https://groups.roblox.com/v1/groups/{group_id}/rolesets/create>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must be the owner of the group.
+    /// * Creating a role costs robux.
+    ///
+    /// # Argument Notes
+    /// * `rank` is a number from 1 to 254 that no other role in the group has.
+    /// * If `using_group_funds` is true, the cost is paid from the group's funds instead of the
+    ///   authenticated user's robux.
+    ///
+    /// # Return Value Notes
+    /// * Returns the created role.
+    /// * [`Role::member_count`] is 0 if Roblox does not return it.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let role = client
+    ///     .create_group_role(
+    ///         GROUP_ID,
+    ///         "Moderator".to_string(),
+    ///         "Moderates the group wall.".to_string(),
+    ///         100,
+    ///         false,
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("Created role {} with id {}.", role.name, role.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_group_role(
+        &self,
+        group_id: u64,
+        name: String,
+        description: String,
+        rank: u8,
+        using_group_funds: bool,
+    ) -> Result<Role, RoboatError> {
+        let body = request_types::RoleReqBody {
+            name,
+            description,
+            rank,
+            using_group_funds: Some(using_group_funds),
+        };
+
+        let role = match self.create_group_role_internal(group_id, &body).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.create_group_role_internal(group_id, &body).await
+                }
+                _ => Err(e),
+            },
+        }?;
+
+        self.remove_cached_group_roles(group_id).await;
+
+        Ok(role)
+    }
+
+    /// Updates the name, description, and rank of a group role using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/rolesets/{role_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must be the owner of the group.
+    /// * Used to both rename and reorder roles, as Roblox requires all three fields to be sent.
+    ///
+    /// # Argument Notes
+    /// * `rank` is a number from 1 to 254 that no other role in the group has.
+    ///
+    /// # Return Value Notes
+    /// * Returns the updated role.
+    /// * [`Role::member_count`] is 0 if Roblox does not return it.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::RoleNotFound`], and [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const ROLE_ID: u64 = 78505465;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let role = client
+    ///     .update_group_role(
+    ///         GROUP_ID,
+    ///         ROLE_ID,
+    ///         "Senior Moderator".to_string(),
+    ///         "Moderates the group wall.".to_string(),
+    ///         150,
+    ///     )
+    ///     .await?;
+    ///
+    /// println!("Role {} now has rank {}.", role.name, role.rank);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_group_role(
+        &self,
+        group_id: u64,
+        role_id: u64,
+        name: String,
+        description: String,
+        rank: u8,
+    ) -> Result<Role, RoboatError> {
+        let body = request_types::RoleReqBody {
+            name,
+            description,
+            rank,
+            using_group_funds: None,
+        };
+
+        let role = match self
+            .update_group_role_internal(group_id, role_id, &body)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.update_group_role_internal(group_id, role_id, &body)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }?;
+
+        self.remove_cached_group_roles(group_id).await;
+
+        Ok(role)
+    }
+
+    /// Deletes a group role using <https://groups.roblox.com/v1/groups/{group_id}/rolesets/{role_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must be the owner of the group.
+    /// * The role must not have any members.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::RoleNotFound`], and [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const ROLE_ID: u64 = 78505465;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.delete_group_role(GROUP_ID, ROLE_ID).await?;
+    ///
+    /// println!("Deleted role {}.", ROLE_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_group_role(&self, group_id: u64, role_id: u64) -> Result<(), RoboatError> {
+        match self.delete_group_role_internal(group_id, role_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.delete_group_role_internal(group_id, role_id).await
+                }
+                _ => Err(e),
+            },
+        }?;
+
+        self.remove_cached_group_roles(group_id).await;
+
+        Ok(())
+    }
+
+    /// Fetches the permissions of a group role using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/roles/{role_id}/permissions>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The authenticated user must be the owner of the group, or have the role.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::RoleNotFound`], and [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const ROLE_ID: u64 = 78505465;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let permissions = client.group_role_permissions(GROUP_ID, ROLE_ID).await?;
+    ///
+    /// println!("Can Post To Wall: {}", permissions.post_to_wall);
+    /// println!("Can Spend Group Funds: {}", permissions.spend_group_funds);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_role_permissions(
+        &self,
+        group_id: u64,
+        role_id: u64,
+    ) -> Result<RolePermissions, RoboatError> {
+        let formatted_url = ROLE_PERMISSIONS_API
+            .replace("{group_id}", &group_id.to_string())
+            .replace("{role_id}", &role_id.to_string());
+
+        let cookie = self.cookie_string()?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(|e| group_error(e, ROLE_PERMISSIONS_ERRORS))?;
+        let raw = Self::parse_to_raw::<request_types::RolePermissionsResponse>(response).await?;
+
+        Ok(RolePermissions::from(raw.permissions))
+    }
+
+    /// Sets the permissions of a group role using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/roles/{role_id}/permissions>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must be the owner of the group.
+    /// * Every permission is sent, so fetch the current permissions with
+    ///   [`Client::group_role_permissions`] to change only some of them.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::RoleNotFound`], and [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const ROLE_ID: u64 = 78505465;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let mut permissions = client.group_role_permissions(GROUP_ID, ROLE_ID).await?;
+    /// permissions.delete_from_wall = true;
+    ///
+    /// client
+    ///     .set_group_role_permissions(GROUP_ID, ROLE_ID, permissions)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_group_role_permissions(
+        &self,
+        group_id: u64,
+        role_id: u64,
+        permissions: RolePermissions,
+    ) -> Result<(), RoboatError> {
+        match self
+            .set_group_role_permissions_internal(group_id, role_id, permissions)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_group_role_permissions_internal(group_id, role_id, permissions)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Fetches the details of a group using <https://groups.roblox.com/v1/groups/{group_id}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let details = client.group_details(GROUP_ID).await?;
+    ///
+    /// println!("Group: {} / Members: {}", details.name, details.member_count);
+    ///
+    /// if let Some(shout) = details.shout {
+    ///     println!("Shout: {}", shout.body);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_details(&self, group_id: u64) -> Result<GroupDetails, RoboatError> {
+        let formatted_url = GROUP_DETAILS_API.replace("{group_id}", &group_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::GroupDetailsResponse>(response).await?;
+
+        Ok(GroupDetails::from(raw))
+    }
+
+    /// Posts a new shout on a group using <https://groups.roblox.com/v1/groups/{group_id}/status>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to post shouts in the group.
+    /// * An empty message clears the shout.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.set_group_shout(GROUP_ID, "Hello world!".to_string()).await?;
+    ///
+    /// println!("Posted shout to group {}.", GROUP_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_group_shout(&self, group_id: u64, message: String) -> Result<(), RoboatError> {
+        match self
+            .set_group_shout_internal(group_id, message.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_group_shout_internal(group_id, message).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Sets the description of a group using <https://groups.roblox.com/v1/groups/{group_id}/description>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to manage the group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client
+    ///     .set_group_description(GROUP_ID, "A new description.".to_string())
+    ///     .await?;
+    ///
+    /// println!("Updated the description of group {}.", GROUP_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_group_description(
+        &self,
+        group_id: u64,
+        description: String,
+    ) -> Result<(), RoboatError> {
+        match self
+            .set_group_description_internal(group_id, description.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_group_description_internal(group_id, description)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Fetches a page of the pending join requests of a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/join-requests>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The authenticated user must have permission to manage join requests in the group.
+    /// * Join requests are ordered oldest to newest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of join requests.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (join_requests, next_cursor) = client
+    ///     .group_join_requests(GROUP_ID, Limit::Hundred, None)
+    ///     .await?;
+    ///
+    /// for join_request in join_requests {
+    ///     println!(
+    ///         "{} requested to join at {}",
+    ///         join_request.requester.username, join_request.created
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_join_requests(
+        &self,
+        group_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<JoinRequest>, Option<String>), RoboatError> {
+        let formatted_url = JOIN_REQUESTS_API
+            .replace("{group_id}", &group_id.to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default())
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{sort_order}", JOIN_REQUESTS_SORT_ORDER);
+
+        let cookie = self.cookie_string()?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(|e| group_error(e, JOIN_REQUEST_ERRORS))?;
+        let raw = Self::parse_to_raw::<request_types::JoinRequestsResponse>(response).await?;
+
+        let join_requests = raw
+            .data
+            .into_iter()
+            .map(|join_request| JoinRequest {
+                requester: User::from(join_request.requester),
+                created: join_request.created,
+            })
+            .collect();
+
+        Ok((join_requests, raw.next_page_cursor))
+    }
+
+    /// Accepts a user's request to join a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/join-requests/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to manage join requests in the group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::UserNotFound`], [`GroupError::InsufficientPermissions`], and
+    ///   [`GroupError::JoinRequestNotFound`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const USER_ID: u64 = 123456789;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.accept_group_join_request(GROUP_ID, USER_ID).await?;
+    ///
+    /// println!("Accepted {} into group {}.", USER_ID, GROUP_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_group_join_request(
+        &self,
+        group_id: u64,
+        user_id: u64,
+    ) -> Result<(), RoboatError> {
+        match self
+            .accept_group_join_request_internal(group_id, user_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.accept_group_join_request_internal(group_id, user_id)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Declines a user's request to join a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/join-requests/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to manage join requests in the group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::UserNotFound`], [`GroupError::InsufficientPermissions`], and
+    ///   [`GroupError::JoinRequestNotFound`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const USER_ID: u64 = 123456789;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.decline_group_join_request(GROUP_ID, USER_ID).await?;
+    ///
+    /// println!("Declined {}'s request to join group {}.", USER_ID, GROUP_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn decline_group_join_request(
+        &self,
+        group_id: u64,
+        user_id: u64,
+    ) -> Result<(), RoboatError> {
+        match self
+            .decline_group_join_request_internal(group_id, user_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.decline_group_join_request_internal(group_id, user_id)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Accepts multiple users' requests to join a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/join-requests>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to manage join requests in the group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::UserNotFound`], [`GroupError::InsufficientPermissions`], and
+    ///   [`GroupError::JoinRequestNotFound`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (join_requests, _) = client
+    ///     .group_join_requests(GROUP_ID, Limit::Hundred, None)
+    ///     .await?;
+    ///
+    /// let user_ids = join_requests
+    ///     .iter()
+    ///     .map(|join_request| join_request.requester.user_id)
+    ///     .collect();
+    ///
+    /// client.accept_group_join_requests(GROUP_ID, user_ids).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_group_join_requests(
+        &self,
+        group_id: u64,
+        user_ids: Vec<u64>,
+    ) -> Result<(), RoboatError> {
+        match self
+            .accept_group_join_requests_internal(group_id, user_ids.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.accept_group_join_requests_internal(group_id, user_ids)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Declines multiple users' requests to join a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/join-requests>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to manage join requests in the group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::UserNotFound`], [`GroupError::InsufficientPermissions`], and
+    ///   [`GroupError::JoinRequestNotFound`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let user_ids = vec![123456789, 987654321];
+    ///
+    /// client.decline_group_join_requests(GROUP_ID, user_ids).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn decline_group_join_requests(
+        &self,
+        group_id: u64,
+        user_ids: Vec<u64>,
+    ) -> Result<(), RoboatError> {
+        match self
+            .decline_group_join_requests_internal(group_id, user_ids.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.decline_group_join_requests_internal(group_id, user_ids)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Returns every group a user is in, along with the user's role in each, using
+    /// <https://groups.roblox.com/v1/users/{user_id}/groups/roles>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * The member count of each returned [`Role`] is always 0.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_groups = client.user_groups(USER_ID).await?;
+    ///
+    /// for user_group in user_groups {
+    ///     println!(
+    ///         "Group: {} / Role: {} / Rank: {}",
+    ///         user_group.group.name, user_group.role.name, user_group.role.rank
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_groups(&self, user_id: u64) -> Result<Vec<UserGroup>, RoboatError> {
+        let formatted_url = USER_GROUPS_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::UserGroupsResponse>(response).await?;
+
+        let user_groups = raw.data.into_iter().map(UserGroup::from).collect();
+
+        Ok(user_groups)
+    }
+
+    /// Returns the primary group of a user, along with the user's role in it, using
+    /// <https://groups.roblox.com/v1/users/{user_id}/groups/primary/role>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * The member count of the returned [`Role`] is always 0.
+    ///
+    /// # Return Value Notes
+    /// * Returns `None` if the user has not set a primary group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// match client.primary_group(USER_ID).await? {
+    ///     Some(user_group) => println!("Primary Group: {}", user_group.group.name),
+    ///     None => println!("User has no primary group."),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn primary_group(&self, user_id: u64) -> Result<Option<UserGroup>, RoboatError> {
+        let formatted_url = PRIMARY_GROUP_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+
+        // Roblox responds with `null` if the user has no primary group.
+        let raw = Self::parse_to_raw::<Option<request_types::UserGroupRaw>>(response).await?;
+
+        Ok(raw.map(UserGroup::from))
+    }
+
+    /// Fetches a page of a group's audit log using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/audit-log>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The authenticated user must have permission to view the audit log of the group.
+    /// * Entries are ordered newest to oldest.
+    ///
+    /// # Argument Notes
+    /// * `action_type_filter` only returns actions of the given type. All actions are returned if it is `None`.
+    /// * `user_filter` only returns actions performed by the given user id. Actions by all users
+    ///   are returned if it is `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of audit log entries.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::groups::{AuditLogAction, AuditLogActionType};
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (entries, next_cursor) = client
+    ///     .group_audit_log(
+    ///         GROUP_ID,
+    ///         Some(AuditLogActionType::ChangeRank),
+    ///         None,
+    ///         Limit::Hundred,
+    ///         None,
+    ///     )
+    ///     .await?;
+    ///
+    /// for entry in entries {
+    ///     if let AuditLogAction::ChangeRank {
+    ///         target_name,
+    ///         new_role_name,
+    ///         ..
+    ///     } = entry.action
+    ///     {
+    ///         let actor = entry.actor.map(|actor| actor.username).unwrap_or_default();
+    ///         println!("{} ranked {} to {}", actor, target_name, new_role_name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_audit_log(
+        &self,
+        group_id: u64,
+        action_type_filter: Option<AuditLogActionType>,
+        user_filter: Option<u64>,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<AuditLogEntry>, Option<String>), RoboatError> {
+        let mut formatted_url = AUDIT_LOG_API
+            .replace("{group_id}", &group_id.to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default())
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{sort_order}", AUDIT_LOG_SORT_ORDER);
+
+        if let Some(action_type) = action_type_filter {
+            formatted_url.push_str(&format!("&actionType={}", action_type));
+        }
+
+        if let Some(user_id) = user_filter {
+            formatted_url.push_str(&format!("&userId={}", user_id));
+        }
+
+        let cookie = self.cookie_string()?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(|e| group_error(e, AUDIT_LOG_ERRORS))?;
+        let raw = Self::parse_to_raw::<request_types::AuditLogResponse>(response).await?;
+
+        let entries = raw.data.into_iter().map(AuditLogEntry::from).collect();
+
+        Ok((entries, raw.next_page_cursor))
+    }
+
+    /// Fetches a page of the posts on a group wall using
+    /// <https://groups.roblox.com/v2/groups/{group_id}/wall/posts>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * A valid roblosecurity is required if the group wall is only visible to members.
+    /// * Posts are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of wall posts.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let (posts, next_cursor) = client
+    ///     .group_wall_posts(GROUP_ID, Limit::Hundred, None)
+    ///     .await?;
+    ///
+    /// for post in posts {
+    ///     let poster = post.poster.map(|poster| poster.username);
+    ///     println!("{:?}: {}", poster, post.body);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_wall_posts(
+        &self,
+        group_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<WallPost>, Option<String>), RoboatError> {
+        let formatted_url = WALL_POSTS_API
+            .replace("{group_id}", &group_id.to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default())
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{sort_order}", WALL_POSTS_SORT_ORDER);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(|e| group_error(e, WALL_ERRORS))?;
+        let raw = Self::parse_to_raw::<request_types::WallPostsResponse>(response).await?;
+
+        let posts = raw.data.into_iter().map(WallPost::from).collect();
+
+        Ok((posts, raw.next_page_cursor))
+    }
+
+    /// Posts a message to a group wall using
+    /// <https://groups.roblox.com/v2/groups/{group_id}/wall/posts>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to post to the group wall.
+    /// * Roblox may require a captcha to be solved, which roboat does not support.
+    ///
+    /// # Return Value Notes
+    /// * Returns the post that was created.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let post = client
+    ///     .post_to_group_wall(GROUP_ID, "Hello world!".to_string())
+    ///     .await?;
+    ///
+    /// println!("Created post {}.", post.post_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn post_to_group_wall(
+        &self,
+        group_id: u64,
+        body: String,
+    ) -> Result<WallPost, RoboatError> {
+        match self
+            .post_to_group_wall_internal(group_id, body.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.post_to_group_wall_internal(group_id, body).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Deletes a post from a group wall using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/wall/posts/{post_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to delete group wall posts.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const POST_ID: u64 = 2845523091;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.delete_group_wall_post(GROUP_ID, POST_ID).await?;
+    ///
+    /// println!("Deleted post {}.", POST_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_group_wall_post(
+        &self,
+        group_id: u64,
+        post_id: u64,
+    ) -> Result<(), RoboatError> {
+        match self
+            .delete_group_wall_post_internal(group_id, post_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.delete_group_wall_post_internal(group_id, post_id)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Deletes every post a user has made on a group wall using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/wall/users/{user_id}/posts>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to delete group wall posts.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`],
+    ///   [`GroupError::InsufficientPermissions`], and [`GroupError::UserNotFound`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const USER_ID: u64 = 123456789;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client
+    ///     .delete_group_wall_posts_by_user(GROUP_ID, USER_ID)
+    ///     .await?;
+    ///
+    /// println!("Deleted all posts by {}.", USER_ID);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_group_wall_posts_by_user(
+        &self,
+        group_id: u64,
+        user_id: u64,
+    ) -> Result<(), RoboatError> {
+        match self
+            .delete_group_wall_posts_by_user_internal(group_id, user_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.delete_group_wall_posts_by_user_internal(group_id, user_id)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Fetches the users receiving recurring payouts from a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/payouts>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The authenticated user must have permission to view the group's payouts.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let recipients = client.group_payout_recipients(GROUP_ID).await?;
+    ///
+    /// for recipient in recipients {
+    ///     println!("{}: {}%", recipient.user.username, recipient.percentage);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_payout_recipients(
+        &self,
+        group_id: u64,
+    ) -> Result<Vec<PayoutRecipient>, RoboatError> {
+        let formatted_url = PAYOUTS_API.replace("{group_id}", &group_id.to_string());
+
+        let cookie = self.cookie_string()?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(|e| group_error(e, PAYOUT_ERRORS))?;
+        let raw = Self::parse_to_raw::<request_types::PayoutRecipientsResponse>(response).await?;
+
+        let recipients = raw
+            .data
+            .into_iter()
+            .map(|recipient| PayoutRecipient {
+                user: User::from(recipient.user),
+                percentage: recipient.percentage,
+            })
+            .collect();
+
+        Ok(recipients)
+    }
+
+    /// Pays out robux from a group's funds to its members using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/payouts>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to spend the group's funds.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::PayoutError`] - Nested inside this error, [`PayoutError::InsufficientFunds`],
+    ///   [`PayoutError::RecipientNotEligible`], and [`PayoutError::ChallengeRequired`] may be thrown.
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::groups::PayoutArgs;
+    /// use roboat::{ClientBuilder, PayoutError, RoboatError};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    /// const USER_ID: u64 = 123456789;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let payout = PayoutArgs {
+    ///     user_id: USER_ID,
+    ///     amount: 100,
+    /// };
+    ///
+    /// match client.group_payout(GROUP_ID, vec![payout]).await {
+    ///     Ok(_) => println!("Paid out 100 robux to {}.", USER_ID),
+    ///     Err(RoboatError::PayoutError(PayoutError::InsufficientFunds)) => {
+    ///         println!("The group does not have enough robux.")
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_payout(
+        &self,
+        group_id: u64,
+        payouts: Vec<PayoutArgs>,
+    ) -> Result<(), RoboatError> {
+        match self.group_payout_internal(group_id, payouts.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.group_payout_internal(group_id, payouts).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Sets the users receiving a percentage of a group's revenue as a recurring payout using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/payouts/recurring>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The authenticated user must have permission to spend the group's funds.
+    /// * This replaces all existing recurring payouts. Users left out stop receiving payouts.
+    ///
+    /// # Argument Notes
+    /// * The percentages must add up to 100 or less, otherwise
+    ///   [`PayoutError::InvalidPercentages`] is returned without sending a request.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::PayoutError`] - Nested inside this error, all variants of [`PayoutError`] may be thrown.
+    /// * [`RoboatError::GroupError`] - Nested inside this error, [`GroupError::GroupNotFound`] and
+    ///   [`GroupError::InsufficientPermissions`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::groups::RecurringPayoutArgs;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const GROUP_ID: u64 = 1127093;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let payouts = vec![
+    ///     RecurringPayoutArgs {
+    ///         user_id: 123456789,
+    ///         percentage: 60,
+    ///     },
+    ///     RecurringPayoutArgs {
+    ///         user_id: 987654321,
+    ///         percentage: 40,
+    ///     },
+    /// ];
+    ///
+    /// client.set_group_recurring_payouts(GROUP_ID, payouts).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_group_recurring_payouts(
+        &self,
+        group_id: u64,
+        payouts: Vec<RecurringPayoutArgs>,
+    ) -> Result<(), RoboatError> {
+        let total_percentage: u64 = payouts.iter().map(|payout| payout.percentage as u64).sum();
+
+        if total_percentage > 100 {
+            return Err(RoboatError::PayoutError(PayoutError::InvalidPercentages));
+        }
+
+        match self
+            .set_group_recurring_payouts_internal(group_id, payouts.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_group_recurring_payouts_internal(group_id, payouts)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+/// Converts a Roblox error code into a [`GroupError`] using the codes of a single endpoint,
+/// as Roblox gives the same code different meanings across the group endpoints.
+fn group_error(error: RoboatError, codes: &[(u16, GroupError)]) -> RoboatError {
+    match error {
+        RoboatError::UnknownRobloxErrorCode { code, message } => {
+            match codes.iter().find(|(x, _)| *x == code) {
+                Some((_, group_error)) => RoboatError::GroupError(group_error.clone()),
+                None => RoboatError::UnknownRobloxErrorCode { code, message },
+            }
+        }
+        _ => error,
+    }
+}
+
+/// Converts the Roblox errors thrown by the payout endpoints into a [`PayoutError`],
+/// falling back to [`group_error`] for the rest.
+fn payout_error(error: RoboatError) -> RoboatError {
+    match error {
+        RoboatError::UnknownRobloxErrorCode { code, message } => {
+            let payout_error = match code {
+                12 => PayoutError::InsufficientFunds,
+                24 => PayoutError::RecipientNotEligible,
+                _ => {
+                    return group_error(
+                        RoboatError::UnknownRobloxErrorCode { code, message },
+                        PAYOUT_ERRORS,
+                    )
+                }
+            };
+
+            RoboatError::PayoutError(payout_error)
+        }
+        RoboatError::ChallengeRequired(challenge_id) => {
+            RoboatError::PayoutError(PayoutError::ChallengeRequired(challenge_id))
+        }
+        _ => error,
+    }
+}
+
+mod internal {
+    use super::{
+        group_error, payout_error, request_types, GroupError, PayoutArgs, RecurringPayoutArgs,
+        Role, RolePermissions, WallPost, CHANGE_GROUP_MEMBER_ROLE_API,
+        CHANGE_GROUP_MEMBER_ROLE_ERRORS, CREATE_ROLE_API, CREATE_ROLE_ERRORS,
+        DELETE_WALL_POSTS_BY_USER_API, DELETE_WALL_POSTS_BY_USER_ERRORS, DELETE_WALL_POST_API,
+        EXILE_MEMBER_API, EXILE_MEMBER_ERRORS, GROUP_DESCRIPTION_API, GROUP_DESCRIPTION_ERRORS,
+        GROUP_SHOUT_API, GROUP_SHOUT_ERRORS, JOIN_REQUESTS_BATCH_API, JOIN_REQUEST_API,
+        JOIN_REQUEST_ERRORS, ONE_TIME_PAYOUT_TYPE, PAYOUTS_API, PAYOUT_RECIPIENT_TYPE,
+        POST_TO_WALL_API, RECURRING_PAYOUT_API, RECURRING_PAYOUT_TYPE, ROLE_API, ROLE_ERRORS,
+        ROLE_PERMISSIONS_API, ROLE_PERMISSIONS_ERRORS, WALL_ERRORS,
+    };
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn set_group_member_role_internal(
+            &self,
+            user_id: u64,
+            group_id: u64,
+            role_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = CHANGE_GROUP_MEMBER_ROLE_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{user_id}", &user_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let json = serde_json::json!({ "roleId": role_id });
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(&json)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_group_shout_internal(
+            &self,
+            group_id: u64,
+            message: String,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = GROUP_SHOUT_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let json = serde_json::json!({ "message": message });
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(&json)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, GROUP_SHOUT_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_group_description_internal(
+            &self,
+            group_id: u64,
+            description: String,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = GROUP_DESCRIPTION_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let json = serde_json::json!({ "description": description });
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(&json)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, GROUP_DESCRIPTION_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn accept_group_join_request_internal(
+            &self,
+            group_id: u64,
+            user_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = JOIN_REQUEST_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{user_id}", &user_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, JOIN_REQUEST_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn decline_group_join_request_internal(
+            &self,
+            group_id: u64,
+            user_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = JOIN_REQUEST_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{user_id}", &user_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, JOIN_REQUEST_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn accept_group_join_requests_internal(
+            &self,
+            group_id: u64,
+            user_ids: Vec<u64>,
+        ) -> Result<(), RoboatError> {
+            let formatted_url =
+                JOIN_REQUESTS_BATCH_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let body = request_types::JoinRequestsBatchReqBody { user_ids };
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, JOIN_REQUEST_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn decline_group_join_requests_internal(
+            &self,
+            group_id: u64,
+            user_ids: Vec<u64>,
+        ) -> Result<(), RoboatError> {
+            let formatted_url =
+                JOIN_REQUESTS_BATCH_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let body = request_types::JoinRequestsBatchReqBody { user_ids };
+
+            let request_result = self
+                .reqwest_client
+                .delete(formatted_url)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, JOIN_REQUEST_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn post_to_group_wall_internal(
+            &self,
+            group_id: u64,
+            body: String,
+        ) -> Result<WallPost, RoboatError> {
+            let formatted_url = POST_TO_WALL_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let json = serde_json::json!({ "body": body });
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .json(&json)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, WALL_ERRORS))?;
+            let raw = Self::parse_to_raw::<request_types::WallPostRaw>(response).await?;
+
+            Ok(WallPost::from(raw))
+        }
+
+        pub(super) async fn delete_group_wall_post_internal(
+            &self,
+            group_id: u64,
+            post_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = DELETE_WALL_POST_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{post_id}", &post_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, WALL_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn delete_group_wall_posts_by_user_internal(
+            &self,
+            group_id: u64,
+            user_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = DELETE_WALL_POSTS_BY_USER_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{user_id}", &user_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, DELETE_WALL_POSTS_BY_USER_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn group_payout_internal(
+            &self,
+            group_id: u64,
+            payouts: Vec<PayoutArgs>,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = PAYOUTS_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let recipients = payouts
+                .into_iter()
+                .map(|payout| request_types::PayoutRecipientReq {
+                    recipient_id: payout.user_id,
+                    recipient_type: PAYOUT_RECIPIENT_TYPE.to_string(),
+                    amount: payout.amount,
+                })
+                .collect();
+
+            let body = request_types::PayoutReqBody {
+                payout_type: ONE_TIME_PAYOUT_TYPE.to_string(),
+                recipients,
+            };
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(payout_error)?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_group_recurring_payouts_internal(
+            &self,
+            group_id: u64,
+            payouts: Vec<RecurringPayoutArgs>,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = RECURRING_PAYOUT_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let recipients = payouts
+                .into_iter()
+                .map(|payout| request_types::PayoutRecipientReq {
+                    recipient_id: payout.user_id,
+                    recipient_type: PAYOUT_RECIPIENT_TYPE.to_string(),
+                    amount: payout.percentage as u64,
+                })
+                .collect();
+
+            let body = request_types::PayoutReqBody {
+                payout_type: RECURRING_PAYOUT_TYPE.to_string(),
+                recipients,
+            };
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(payout_error)?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn exile_member_internal(
+            &self,
+            user_id: u64,
+            group_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = EXILE_MEMBER_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{user_id}", &user_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, EXILE_MEMBER_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        /// Returns the roles of a group from the client's cache, fetching and caching
+        /// them if they are not cached or if `refresh` is true.
+        pub(super) async fn group_roles_cached(
+            &self,
+            group_id: u64,
+            refresh: bool,
+        ) -> Result<Vec<Role>, RoboatError> {
+            if !refresh {
+                if let Some(roles) = self.cached_group_roles(group_id).await {
+                    return Ok(roles);
+                }
+            }
+
+            let roles = self.group_roles(group_id).await?;
+            self.set_cached_group_roles(group_id, roles.clone()).await;
+
+            Ok(roles)
+        }
+
+        /// Moves a member one role up if `up` is true, otherwise one role down.
+        /// The guest role (rank 0) is never moved to.
+        pub(super) async fn shift_rank_internal(
+            &self,
+            user_id: u64,
+            group_id: u64,
+            up: bool,
+        ) -> Result<Role, RoboatError> {
+            let current_rank = self
+                .user_groups(user_id)
+                .await?
+                .into_iter()
+                .find(|user_group| user_group.group.group_id == group_id)
+                .map(|user_group| user_group.role.rank)
+                .ok_or(RoboatError::GroupError(GroupError::UserNotInGroup))?;
+
+            // Roles are sorted in ascending order by rank. Members are never
+            // promoted to the owner role (rank 255) or demoted to the guest role (rank 0).
+            let next_role = |roles: Vec<Role>| match up {
+                true => roles
+                    .into_iter()
+                    .find(|role| role.rank > current_rank && role.rank != 255),
+                false => roles
+                    .into_iter()
+                    .rev()
+                    .find(|role| role.rank < current_rank && role.rank != 0),
+            };
+
+            let mut new_role = next_role(self.group_roles_cached(group_id, false).await?);
+
+            // The cached roles may be out of date, so they are fetched again before giving up.
+            if new_role.is_none() {
+                new_role = next_role(self.group_roles_cached(group_id, true).await?);
+            }
+
+            let new_role = new_role.ok_or(RoboatError::GroupError(GroupError::RoleNotFound))?;
+
+            self.set_group_member_role(user_id, group_id, new_role.id)
+                .await
+                .map_err(|e| group_error(e, CHANGE_GROUP_MEMBER_ROLE_ERRORS))?;
+
+            Ok(new_role)
+        }
+
+        pub(super) async fn create_group_role_internal(
+            &self,
+            group_id: u64,
+            body: &request_types::RoleReqBody,
+        ) -> Result<Role, RoboatError> {
+            let formatted_url = CREATE_ROLE_API.replace("{group_id}", &group_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .json(body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, CREATE_ROLE_ERRORS))?;
+            let raw = Self::parse_to_raw::<request_types::RoleRaw>(response).await?;
+
+            Ok(Role::from(raw))
+        }
+
+        pub(super) async fn update_group_role_internal(
+            &self,
+            group_id: u64,
+            role_id: u64,
+            body: &request_types::RoleReqBody,
+        ) -> Result<Role, RoboatError> {
+            let formatted_url = ROLE_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{role_id}", &role_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, ROLE_ERRORS))?;
+            let raw = Self::parse_to_raw::<request_types::RoleRaw>(response).await?;
+
+            Ok(Role::from(raw))
+        }
+
+        pub(super) async fn delete_group_role_internal(
+            &self,
+            group_id: u64,
+            role_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = ROLE_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{role_id}", &role_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, ROLE_ERRORS))?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_group_role_permissions_internal(
+            &self,
+            group_id: u64,
+            role_id: u64,
+            permissions: RolePermissions,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = ROLE_PERMISSIONS_API
+                .replace("{group_id}", &group_id.to_string())
+                .replace("{role_id}", &role_id.to_string());
+
+            let cookie = self.cookie_string()?;
+            let xcsrf = self.xcsrf().await;
+
+            let body = request_types::RolePermissionsReqBody {
+                permissions: permissions.into(),
+            };
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(&body)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result)
+                .await
+                .map_err(|e| group_error(e, ROLE_PERMISSIONS_ERRORS))?;
 
             // If we got a status code 200, it was successful.
 
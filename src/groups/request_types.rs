@@ -24,3 +24,324 @@ pub(super) struct MemberRaw {
     pub username: String,
     pub display_name: String,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupDetailsResponse {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    pub owner: Option<GroupUserRaw>,
+    pub shout: Option<GroupShoutRaw>,
+    pub member_count: u64,
+    #[serde(default)]
+    pub public_entry_allowed: bool,
+    #[serde(default)]
+    pub is_locked: bool,
+    #[serde(default)]
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupUserRaw {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupShoutRaw {
+    pub body: String,
+    pub poster: Option<GroupUserRaw>,
+    pub created: String,
+    pub updated: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct JoinRequestsResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<JoinRequestRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct JoinRequestRaw {
+    pub requester: GroupUserRaw,
+    pub created: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct JoinRequestsBatchReqBody {
+    #[serde(rename = "UserIds")]
+    pub user_ids: Vec<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserGroupsResponse {
+    pub data: Vec<UserGroupRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserGroupRaw {
+    pub group: GroupDetailsResponse,
+    pub role: UserGroupRoleRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserGroupRoleRaw {
+    pub id: u64,
+    pub name: String,
+    pub rank: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AuditLogResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<AuditLogEntryRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AuditLogEntryRaw {
+    pub actor: Option<AuditLogActorRaw>,
+    pub action_type: String,
+    pub description: Option<serde_json::Value>,
+    pub created: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupActorRaw {
+    pub user: GroupUserRaw,
+    pub role: UserGroupRoleRaw,
+}
+
+/// Roblox can omit the actor, or parts of it, such as for deleted accounts.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AuditLogActorRaw {
+    pub user: Option<GroupUserRaw>,
+    pub role: Option<UserGroupRoleRaw>,
+}
+
+// [Audit log description structs]
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct ChangeRankDescriptionRaw {
+    pub target_id: u64,
+    pub target_name: String,
+    pub old_role_set_id: u64,
+    pub old_role_set_name: String,
+    pub new_role_set_id: u64,
+    pub new_role_set_name: String,
+}
+
+/// Shared by the actions that only target a user or item, such as accepting join requests.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct TargetDescriptionRaw {
+    pub target_id: u64,
+    #[serde(default)]
+    pub target_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct PostStatusDescriptionRaw {
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct DeletePostDescriptionRaw {
+    pub target_id: u64,
+    #[serde(default)]
+    pub target_name: String,
+    #[serde(default)]
+    pub post_desc: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct SpendGroupFundsDescriptionRaw {
+    pub amount: u64,
+    pub currency_type: u64,
+    #[serde(default)]
+    pub item_description: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct ChangeDescriptionDescriptionRaw {
+    pub new_description: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct RenameDescriptionRaw {
+    pub new_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct WallPostsResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<WallPostRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct WallPostRaw {
+    pub id: u64,
+    pub poster: Option<GroupActorRaw>,
+    pub body: String,
+    pub created: String,
+    pub updated: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PayoutRecipientsResponse {
+    pub data: Vec<PayoutRecipientRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PayoutRecipientRaw {
+    pub user: GroupUserRaw,
+    pub percentage: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct PayoutReqBody {
+    pub payout_type: String,
+    pub recipients: Vec<PayoutRecipientReq>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PayoutRecipientReq {
+    pub recipient_id: u64,
+    pub recipient_type: String,
+    pub amount: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RoleReqBody {
+    pub name: String,
+    pub description: String,
+    pub rank: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub using_group_funds: Option<bool>,
+}
+
+/// Returned when creating or updating a role. Roblox does not always include the member count.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RoleRaw {
+    pub id: u64,
+    pub name: String,
+    pub rank: u8,
+    pub member_count: Option<u64>,
+}
+
+// [Role permissions structs]
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RolePermissionsResponse {
+    pub permissions: RolePermissionsRaw,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RolePermissionsRaw {
+    #[serde(default)]
+    pub group_posts_permissions: PostsPermissionsRaw,
+    #[serde(default)]
+    pub group_membership_permissions: MembershipPermissionsRaw,
+    #[serde(default)]
+    pub group_management_permissions: ManagementPermissionsRaw,
+    #[serde(default)]
+    pub group_economy_permissions: EconomyPermissionsRaw,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(super) struct PostsPermissionsRaw {
+    pub view_wall: bool,
+    pub post_to_wall: bool,
+    pub delete_from_wall: bool,
+    pub view_status: bool,
+    pub post_to_status: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(super) struct MembershipPermissionsRaw {
+    pub change_rank: bool,
+    pub invite_members: bool,
+    pub remove_members: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(super) struct ManagementPermissionsRaw {
+    pub manage_relationships: bool,
+    pub view_audit_logs: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(super) struct EconomyPermissionsRaw {
+    pub spend_group_funds: bool,
+    pub advertise_group: bool,
+    pub create_items: bool,
+    pub manage_items: bool,
+    pub add_group_places: bool,
+    pub manage_group_games: bool,
+    pub view_group_payouts: bool,
+    pub view_analytics: bool,
+}
+
+/// The endpoint takes the permissions as a flat map of PascalCase permission names.
+#[derive(Serialize, Deserialize)]
+pub(super) struct RolePermissionsReqBody {
+    pub permissions: RolePermissionsReq,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct RolePermissionsReq {
+    pub view_wall: bool,
+    pub post_to_wall: bool,
+    pub delete_from_wall: bool,
+    pub view_status: bool,
+    pub post_to_status: bool,
+    pub change_rank: bool,
+    pub invite_members: bool,
+    pub remove_members: bool,
+    pub manage_relationships: bool,
+    pub view_audit_logs: bool,
+    pub spend_group_funds: bool,
+    pub advertise_group: bool,
+    pub create_items: bool,
+    pub manage_items: bool,
+    pub add_group_places: bool,
+    pub manage_group_games: bool,
+    pub view_group_payouts: bool,
+    pub view_analytics: bool,
+}
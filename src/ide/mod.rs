@@ -42,7 +42,7 @@ impl Client {
     /// # Example
     /// ```no_run
     /// use bytes::Bytes;
-    /// use roboat::{ClientBuilder, ide::request_types::Animation};
+    /// use roboat::{ClientBuilder, ide::ide_types::NewAnimation};
     ///
     /// const ROBLOSECURITY: &str = "your_.ROBLOSECURITY_cookie";
     ///
@@ -52,12 +52,11 @@ impl Client {
     ///     .roblosecurity(ROBLOSECURITY.to_string())
     ///     .build();
     ///
-    /// let animation = Animation {
-    ///     id: None,
-    ///     title: "MyCoolAnimation".to_string(),
+    /// let animation = NewAnimation {
+    ///     name: "MyCoolAnimation".to_string(),
     ///     description: "A test animation created by Roboat.".to_string(),
     ///     group_id: Some(123456),
-    ///     animation_data: Some(Bytes::from_static(b"<KeyframeSequence>...</KeyframeSequence>")),
+    ///     animation_data: Bytes::from_static(b"<KeyframeSequence>...</KeyframeSequence>"),
     /// };
     ///
     /// client.upload_new_animation(animation).await?;
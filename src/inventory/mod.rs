@@ -0,0 +1,374 @@
+use crate::catalog::{AssetType, ItemType};
+use crate::{Client, Limit, RoboatError};
+use reqwest::header::{self, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const COLLECTIBLES_API: &str = "https://inventory.roblox.com/v1/users/{user_id}/assets/collectibles?sortOrder={sort_order}&limit={limit}&cursor={cursor}";
+
+const OWNS_ITEM_API: &str =
+    "https://inventory.roblox.com/v1/users/{user_id}/items/{item_type}/{item_id}/is-owned";
+const INVENTORY_API: &str = "https://inventory.roblox.com/v2/users/{user_id}/inventory?assetTypes={asset_types}&sortOrder={sort_order}&limit={limit}&cursor={cursor}";
+
+/// Inventory items are returned oldest to newest, so that paging through them is stable
+/// while new items are being acquired.
+const INVENTORY_SORT_ORDER: &str = "Asc";
+
+/// Custom Roblox errors that occur when reading the inventory of a user.
+#[non_exhaustive]
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum InventoryError {
+    /// Thrown when the user has hidden their inventory from the requester.
+    /// There is no point in retrying unless the roblosecurity belongs to someone who can see it.
+    #[default]
+    #[error("Inventory Private")]
+    InventoryPrivate,
+    /// Thrown when the user does not exist.
+    #[error("User Not Found")]
+    UserNotFound,
+    /// Thrown when no asset types are given to [`Client::inventory`]. No request is sent.
+    #[error("No Asset Types")]
+    NoAssetTypes,
+}
+
+/// A limited item owned by a user. Retrieved using [`Client::collectibles`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Collectible {
+    /// The unique asset id of the item. This is the id used when sending trades.
+    pub uaid: u64,
+    /// The asset id of the item.
+    pub asset_id: u64,
+    /// The name of the item.
+    pub name: String,
+    /// The serial number of the item. Only limited uniques have a serial number.
+    pub serial_number: Option<u64>,
+    /// The recent average price of the item.
+    pub recent_average_price: u64,
+    /// The price the item originally sold for. This is `None` if it was never sold for robux.
+    pub original_price: Option<u64>,
+    /// The amount of copies originally made. This is `None` for non-unique limiteds.
+    pub asset_stock: Option<u64>,
+    /// Whether the item is on trade hold.
+    pub is_on_hold: bool,
+}
+
+/// An asset owned by a user. Retrieved using [`Client::inventory`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct InventoryItem {
+    /// The unique asset id of this copy of the asset.
+    pub uaid: u64,
+    /// The asset id of the item.
+    pub asset_id: u64,
+    /// The name of the item.
+    pub name: String,
+    /// The type of the asset. This is `None` if the asset type is not known to this crate.
+    pub asset_type: Option<AssetType>,
+    /// The serial number of the item. Only limited uniques have a serial number.
+    pub serial_number: Option<u64>,
+    /// When the user acquired the item, as an ISO 8601 timestamp.
+    pub created: Option<String>,
+}
+
+impl Client {
+    /// Fetches a page of the limited items owned by a user using
+    /// <https://inventory.roblox.com/v1/users/{user_id}/assets/collectibles>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * If a roblosecurity is set, it is sent so that private inventories visible
+    ///   to the authenticated user can be fetched.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of collectibles.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::InventoryError`] - Nested inside this error, all variants of [`InventoryError`] may be thrown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (collectibles, next_cursor) = client
+    ///         .collectibles(USER_ID, Limit::Hundred, cursor)
+    ///         .await?;
+    ///
+    ///     for collectible in collectibles {
+    ///         println!(
+    ///             "{} / UAID: {} / RAP: {}",
+    ///             collectible.name, collectible.uaid, collectible.recent_average_price
+    ///         );
+    ///     }
+    ///
+    ///     cursor = next_cursor;
+    ///
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn collectibles(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Collectible>, Option<String>), RoboatError> {
+        let formatted_url = COLLECTIBLES_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{sort_order}", INVENTORY_SORT_ORDER)
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(inventory_error)?;
+        let raw = Self::parse_to_raw::<request_types::CollectiblesResponse>(response).await?;
+
+        let collectibles = raw
+            .data
+            .into_iter()
+            .map(|item| Collectible {
+                uaid: item.user_asset_id,
+                asset_id: item.asset_id,
+                name: item.name,
+                serial_number: item.serial_number,
+                recent_average_price: item.recent_average_price.unwrap_or_default(),
+                original_price: item.original_price,
+                asset_stock: item.asset_stock,
+                is_on_hold: item.is_on_hold,
+            })
+            .collect();
+
+        Ok((collectibles, raw.next_page_cursor))
+    }
+
+    /// Checks whether a user owns an asset or bundle using
+    /// <https://inventory.roblox.com/v1/users/{user_id}/items/{item_type}/{item_id}/is-owned>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * If a roblosecurity is set, it is sent so that private inventories visible
+    ///   to the authenticated user can be checked.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::InventoryError`] - Nested inside this error, all variants of [`InventoryError`] may be thrown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::catalog::ItemType;
+    ///
+    /// const USER_ID: u64 = 2207291;
+    /// const ASSET_ID: u64 = 1365767;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let owns_item = client.owns_item(USER_ID, ItemType::Asset, ASSET_ID).await?;
+    ///
+    /// println!("User {} owns asset {}: {}", USER_ID, ASSET_ID, owns_item);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn owns_item(
+        &self,
+        user_id: u64,
+        item_type: ItemType,
+        item_id: u64,
+    ) -> Result<bool, RoboatError> {
+        let item_type_str = match item_type {
+            ItemType::Asset => "Asset",
+            ItemType::Bundle => "Bundle",
+        };
+
+        let formatted_url = OWNS_ITEM_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{item_type}", item_type_str)
+            .replace("{item_id}", &item_id.to_string());
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(inventory_error)?;
+        let owns_item = Self::parse_to_raw::<bool>(response).await?;
+
+        Ok(owns_item)
+    }
+
+    /// Fetches a page of the assets of the given types owned by a user using
+    /// <https://inventory.roblox.com/v2/users/{user_id}/inventory>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * If a roblosecurity is set, it is sent so that private inventories visible
+    ///   to the authenticated user can be fetched.
+    ///
+    /// # Argument Notes
+    /// * At least one asset type must be given, otherwise [`InventoryError::NoAssetTypes`] is returned.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of inventory items.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::InventoryError`] - Nested inside this error, all variants of [`InventoryError`] may be thrown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::catalog::AssetType;
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let asset_types = vec![AssetType::Hat, AssetType::HairAccessory];
+    ///
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (items, next_cursor) = client
+    ///         .inventory(USER_ID, asset_types.clone(), Limit::Hundred, cursor)
+    ///         .await?;
+    ///
+    ///     for item in items {
+    ///         println!("{} / Type: {:?}", item.name, item.asset_type);
+    ///     }
+    ///
+    ///     cursor = next_cursor;
+    ///
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn inventory(
+        &self,
+        user_id: u64,
+        asset_types: Vec<AssetType>,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<InventoryItem>, Option<String>), RoboatError> {
+        // An empty filter is rejected by Roblox with a 400 that does not explain why.
+        if asset_types.is_empty() {
+            return Err(RoboatError::InventoryError(InventoryError::NoAssetTypes));
+        }
+
+        let asset_types_str = asset_types
+            .into_iter()
+            .map(|asset_type| u64::from(asset_type).to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let formatted_url = INVENTORY_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{asset_types}", &asset_types_str)
+            .replace("{sort_order}", INVENTORY_SORT_ORDER)
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default());
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result)
+            .await
+            .map_err(inventory_error)?;
+        let raw = Self::parse_to_raw::<request_types::InventoryResponse>(response).await?;
+
+        let items = raw
+            .data
+            .into_iter()
+            .map(|item| {
+                let asset_type = match item.asset_type {
+                    request_types::AssetTypeRaw::Id(id) => AssetType::try_from(id).ok(),
+                    request_types::AssetTypeRaw::Typed(asset_type) => Some(asset_type),
+                    request_types::AssetTypeRaw::Unknown(_) => None,
+                };
+
+                InventoryItem {
+                    uaid: item.user_asset_id,
+                    asset_id: item.asset_id,
+                    name: item.name,
+                    asset_type,
+                    serial_number: item.serial_number,
+                    created: item.created,
+                }
+            })
+            .collect();
+
+        Ok((items, raw.next_page_cursor))
+    }
+}
+
+/// Converts the Roblox errors shared by the inventory endpoints into an [`InventoryError`].
+fn inventory_error(error: RoboatError) -> RoboatError {
+    match error {
+        RoboatError::UnknownRobloxErrorCode { code, message } => match code {
+            1 => RoboatError::InventoryError(InventoryError::UserNotFound),
+            4 => RoboatError::InventoryError(InventoryError::InventoryPrivate),
+            _ => RoboatError::UnknownRobloxErrorCode { code, message },
+        },
+        _ => error,
+    }
+}
@@ -0,0 +1,51 @@
+use crate::catalog::AssetType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CollectiblesResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<CollectibleRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CollectibleRaw {
+    pub user_asset_id: u64,
+    pub serial_number: Option<u64>,
+    pub asset_id: u64,
+    pub name: String,
+    pub recent_average_price: Option<u64>,
+    pub original_price: Option<u64>,
+    pub asset_stock: Option<u64>,
+    pub is_on_hold: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct InventoryResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<InventoryItemRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct InventoryItemRaw {
+    pub asset_id: u64,
+    pub name: String,
+    pub asset_type: AssetTypeRaw,
+    pub user_asset_id: u64,
+    pub serial_number: Option<u64>,
+    pub created: Option<String>,
+}
+
+/// Roblox has returned the asset type both as its numeric id and as its name.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub(super) enum AssetTypeRaw {
+    Id(u64),
+    Typed(AssetType),
+    Unknown(String),
+}
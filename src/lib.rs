@@ -11,12 +11,44 @@
 //! # Coverage
 //! * Auth API
 //!   - Force Refresh Xcsrf - [`Client::force_refresh_xcsrf`]
+//!   - Fetch Authentication Ticket - [`Client::authentication_ticket`]
+//!   - Redeem Authentication Ticket - [`Client::redeem_authentication_ticket`]
+//!   - Revoke Other Sessions - [`Client::revoke_other_sessions`]
+//!   - Login - [`ClientBuilder::login`]
+//!   - Login With Challenge - [`ClientBuilder::login_with_challenge`]
+//!   - Login Two Step - [`ClientBuilder::login_two_step`]
+//! * Avatar API
+//!   - Fetch User Avatar - [`Client::avatar`]
+//!   - Set Worn Assets - [`Client::set_worn_assets`]
+//!   - Wear Asset - [`Client::wear_asset`]
+//!   - Unwear Asset - [`Client::unwear_asset`]
+//!   - Set Body Colors - [`Client::set_body_colors`]
+//!   - Set Avatar Scales - [`Client::set_avatar_scales`]
+//!   - Set Avatar Type - [`Client::set_avatar_type`]
+//!   - Redraw Avatar Thumbnail - [`Client::redraw_avatar_thumbnail`]
+//!   - Fetch User Outfits - [`Client::outfits`]
+//!   - Fetch Outfit Details - [`Client::outfit_details`]
+//!   - Create Outfit - [`Client::create_outfit`]
+//!   - Update Outfit - [`Client::update_outfit`]
+//!   - Rename Outfit - [`Client::rename_outfit`]
+//!   - Delete Outfit - [`Client::delete_outfit`]
+//! * Badges API
+//!   - Fetch Badge Details - [`Client::badge_details`]
+//!   - Fetch Universe Badges - [`Client::universe_badges`]
+//!   - Fetch User Badges - [`Client::user_badges`]
+//!   - Fetch Badge Awarded Dates - [`Client::badge_awarded_dates`]
 //! * BEDEV2 API
 //!   - Fetch Non-Tradable Limited Details - [`Client::non_tradable_limited_details`]
 //!   - Fetch Collectible Product ID - [`Client::collectible_product_id`]
 //!   - Fetch Collectible Product ID Bulk - [`Client::collectible_product_id_bulk`]
 //!   - Fetch Collectible Creator ID - [`Client::collectible_creator_id`]
 //!   - Purchase Non-Tradable Limited - [`Client::purchase_non_tradable_limited`]
+//!   - Fetch Sessions - [`Client::sessions`]
+//!   - Revoke Session - [`Client::revoke_session`]
+//!   - Block User - [`Client::block_user`]
+//!   - Unblock User - [`Client::unblock_user`]
+//!   - Fetch Blocked Users - [`Client::blocked_users`]
+//!   - Check If User Is Blocked - [`Client::is_blocked`]
 //! * Catalog API
 //!   - Fetch Item Details - [`Client::item_details`]
 //!   - Fetch Product ID - [`Client::product_id`]  
@@ -28,6 +60,7 @@
 //!   - Fetch Unread Conversation Count - [`Client::unread_conversation_count`]
 //! * Economy API
 //!   - Fetch Robux Balance - [`Client::robux`]
+//!   - Fetch Group Funds - [`Client::group_funds`]
 //!   - Fetch Resellers - [`Client::resellers`]
 //!   - Fetch User Sales - [`Client::user_sales`]
 //!   - Put Limited On Sale - [`Client::put_limited_on_sale`]
@@ -37,6 +70,38 @@
 //!   - Fetch Group Roles - [`Client::group_roles`]
 //!   - Fetch Group Role Members - [`Client::group_role_members`]
 //!   - Set Group Member Role - [`Client::set_group_member_role`]
+//!   - Fetch Group Details - [`Client::group_details`]
+//!   - Set Group Shout - [`Client::set_group_shout`]
+//!   - Set Group Description - [`Client::set_group_description`]
+//!   - Fetch Group Join Requests - [`Client::group_join_requests`]
+//!   - Accept Group Join Request - [`Client::accept_group_join_request`]
+//!   - Decline Group Join Request - [`Client::decline_group_join_request`]
+//!   - Accept Group Join Requests - [`Client::accept_group_join_requests`]
+//!   - Decline Group Join Requests - [`Client::decline_group_join_requests`]
+//!   - Fetch User Groups - [`Client::user_groups`]
+//!   - Fetch Primary Group - [`Client::primary_group`]
+//!   - Fetch Group Audit Log - [`Client::group_audit_log`]
+//!   - Fetch Group Wall Posts - [`Client::group_wall_posts`]
+//!   - Post To Group Wall - [`Client::post_to_group_wall`]
+//!   - Delete Group Wall Post - [`Client::delete_group_wall_post`]
+//!   - Delete Group Wall Posts By User - [`Client::delete_group_wall_posts_by_user`]
+//!   - Fetch Group Payout Recipients - [`Client::group_payout_recipients`]
+//!   - Group Payout - [`Client::group_payout`]
+//!   - Set Group Recurring Payouts - [`Client::set_group_recurring_payouts`]
+//!   - Set Group Member Rank - [`Client::set_group_member_rank`]
+//!   - Promote Group Member - [`Client::promote`]
+//!   - Demote Group Member - [`Client::demote`]
+//!   - Exile Group Member - [`Client::exile_member`]
+//!   - Leave Group - [`Client::leave_group`]
+//!   - Create Group Role - [`Client::create_group_role`]
+//!   - Update Group Role - [`Client::update_group_role`]
+//!   - Delete Group Role - [`Client::delete_group_role`]
+//!   - Fetch Group Role Permissions - [`Client::group_role_permissions`]
+//!   - Set Group Role Permissions - [`Client::set_group_role_permissions`]
+//! * Inventory API
+//!   - Fetch User Collectibles - [`Client::collectibles`]
+//!   - Check Item Ownership - [`Client::owns_item`]
+//!   - Fetch User Inventory - [`Client::inventory`]
 //! * Presence API
 //!   - Register Presence - [`Client::register_presence`]
 //!   - Fetch Users Presence - [`Client::fetch_users_presence`]
@@ -57,8 +122,14 @@
 //!   - Fetch Username - [`Client::username`]
 //!   - Fetch Display Name - [`Client::display_name`]
 //!   - User Search - [`Client::user_search`]
+//!   - User Search Page - [`Client::user_search_page`]
 //!   - Username User Details - [`Client::username_user_details`]
 //!   - Fetch User Details - [`Client::user_details`]
+//!   - Fetch User Details Bulk - [`Client::user_details_bulk`]
+//!   - Fetch Username History - [`Client::username_history`]
+//!   - Validate Display Name - [`Client::validate_display_name`]
+//!   - Set Display Name - [`Client::set_display_name`]
+//!   - Fetch Session Status - [`Client::session_status`]
 //! * Friends API
 //!   - Fetch Count of Pending Friend Requests - [`Client::pending_friend_requests`]
 //!   - Fetch Friend Requests - [`Client::friend_requests`]
@@ -67,6 +138,17 @@
 //!   - Decline Friend Request - [`Client::decline_friend_request`]
 //!   - Send Friend Request - [`Client::send_friend_request`]
 //!   - Unfriend - [`Client::unfriend`]
+//!   - Fetch Follower Count - [`Client::follower_count`]
+//!   - Fetch Following Count - [`Client::following_count`]
+//!   - Fetch Followers - [`Client::followers`]
+//!   - Fetch Followings - [`Client::followings`]
+//!   - Follow User - [`Client::follow`]
+//!   - Unfollow User - [`Client::unfollow`]
+//!   - Fetch Friend Count - [`Client::friend_count`]
+//!   - Fetch Friends Page - [`Client::friends_page`]
+//!   - Fetch Online Friends - [`Client::online_friends`]
+//!   - Decline All Friend Requests - [`Client::decline_all_friend_requests`]
+//!   - Process Friend Requests - [`Client::process_friend_requests`]
 //! * Assetdelivery API
 //!   - Fetch Asset Data - [`Client::fetch_asset_data`]
 //! * IDE API (Animations)
@@ -213,15 +295,23 @@
 pub use reqwest;
 use serde::{Deserialize, Serialize};
 
+pub use avatar::AvatarError;
 pub use bedev2::PurchaseNonTradableLimitedError;
 pub use client::{Client, ClientBuilder};
 pub use economy::PurchaseTradableLimitedError;
+pub use groups::{GroupError, PayoutError};
+pub use inventory::InventoryError;
+pub use users::UserSearchError;
 
 ///
 /// A module for endpoints prefixed with <https://assetdelivery.roblox.com/*>
 pub mod assetdelivery;
 /// A module for endpoints prefixed with <https://auth.roblox.com/*>.
-mod auth;
+pub mod auth;
+/// A module for endpoints prefixed with <https://avatar.roblox.com/*>.
+pub mod avatar;
+/// A module for endpoints prefixed with <https://badges.roblox.com/*>.
+pub mod badges;
 /// A module for endpoints prefixed with <https://apis.roblox.com/*>.
 pub mod bedev2;
 /// A module for endpoints prefixed with <https://catalog.roblox.com/*>.
@@ -239,6 +329,8 @@ pub mod groups;
 /// A module for endpoints prefixed with <https://www.roblox.com/ide/*>
 // This is used for private APIs like ide/uploadnewanimation and ide/places/createV2
 pub mod ide;
+/// A module for endpoints prefixed with <https://inventory.roblox.com/*>.
+pub mod inventory;
 /// A module for endpoints prefixed with <https://presence.roblox.com/*>.
 pub mod presence;
 /// A module for endpoints prefixed with <https://privatemessages.roblox.com/*>.
@@ -251,7 +343,6 @@ pub mod trades;
 pub mod users;
 /// A module related to validating requests.
 mod validation;
-// todo: figure out authtickets
 // todo: maybe respect cookies returned
 // todo: maybe add stronger types for stuff like cursors? stuff that can be returned basically and is unlikely to cbe created by the user.
 // todo: add doc example and example count somewhere
@@ -364,12 +455,35 @@ pub enum RoboatError {
     #[error("Unknown Status Code 403 Format. If this occurs often it may be a bug. Please report it to the issues page."
     )]
     UnknownStatus403Format,
+    /// Used when logging in to an account that has two step verification enabled.
+    /// The login can be resumed using [`ClientBuilder::login_two_step`].
+    #[error("Two Step Verification Required. A code has been sent using {:?}.", .0.media_type)]
+    TwoStepVerificationRequired(auth::TwoStepVerificationChallenge),
+    /// Used when Roblox requires a challenge (usually a captcha) to be solved before logging in.
+    /// The login can be resumed using [`ClientBuilder::login_with_challenge`].
+    #[error("Login Challenge Required. A {:?} challenge must be completed using challenge id {}.", .0.challenge_type, .0.challenge_id)]
+    LoginChallengeRequired(ChallengeInfo),
     /// Custom Roblox errors sometimes thrown when the user calls [`Client::purchase_tradable_limited`].
     #[error("{0}")]
     PurchaseTradableLimitedError(PurchaseTradableLimitedError),
     /// Custom Roblox errors sometimes thrown when the user calls [`Client::purchase_non_tradable_limited`].
     #[error("{0}")]
     PurchaseNonTradableLimitedError(PurchaseNonTradableLimitedError),
+    /// Custom Roblox errors sometimes thrown when the user calls [`Client::user_search`] or [`Client::user_search_page`].
+    #[error("{0}")]
+    UserSearchError(UserSearchError),
+    /// Custom Roblox errors sometimes thrown when the user edits their avatar, such as with [`Client::set_worn_assets`] or [`Client::wear_asset`].
+    #[error("{0}")]
+    AvatarError(AvatarError),
+    /// Custom Roblox errors sometimes thrown when the user calls [`Client::collectibles`], [`Client::owns_item`], or [`Client::inventory`].
+    #[error("{0}")]
+    InventoryError(InventoryError),
+    /// Custom Roblox errors sometimes thrown when the user manages a group, such as with [`Client::set_group_shout`].
+    #[error("{0}")]
+    GroupError(GroupError),
+    /// Custom Roblox errors sometimes thrown when the user calls [`Client::group_payout`] or [`Client::set_group_recurring_payouts`].
+    #[error("{0}")]
+    PayoutError(PayoutError),
     /// Used for any reqwest error that occurs.
     #[error("RequestError {0}")]
     ReqwestError(reqwest::Error),
@@ -389,6 +503,7 @@ pub enum RoboatError {
 pub enum ChallengeType {
     #[default]
     TwoStep,
+    Captcha,
 }
 
 impl TryFrom<String> for ChallengeType {
@@ -397,11 +512,21 @@ impl TryFrom<String> for ChallengeType {
     fn try_from(raw: String) -> Result<Self, Self::Error> {
         match raw.as_str() {
             "twostepverification" => Ok(ChallengeType::TwoStep),
+            "captcha" => Ok(ChallengeType::Captcha),
             _ => Err(RoboatError::MalformedResponse),
         }
     }
 }
 
+impl std::fmt::Display for ChallengeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TwoStep => write!(f, "twostepverification"),
+            Self::Captcha => write!(f, "captcha"),
+        }
+    }
+}
+
 /// The challenge info returned by Roblox when a challenge is required to complete a request.
 /// This challenge can be either a two step verification code or a captcha. This is specified by the `challenge_type` field.
 #[non_exhaustive]
@@ -52,6 +52,8 @@ pub enum ThumbnailType {
     AvatarHeadshot,
     #[default]
     Asset,
+    Outfit,
+    BadgeIcon,
 }
 
 impl fmt::Display for ThumbnailSize {
@@ -93,7 +95,7 @@ impl Client {
     /// * Can handle up to 100 asset ids at once.
     /// * Does not appear to have a rate limit.
     /// * Note all types are implemented, the full list can be found [here](https://thumbnails.roblox.com/docs/index.html)
-    /// and the implemented ones can be found in [`ThumbnailType`].
+    ///   and the implemented ones can be found in [`ThumbnailType`].
     ///
     /// # Errors
     /// * All errors under [Standard Errors](#standard-errors).
@@ -199,7 +201,7 @@ impl Client {
     /// * Can handle up to 100 asset ids at once.
     /// * Does not appear to have a rate limit.
     /// * Note all types are implemented, the full list can be found [here](https://thumbnails.roblox.com/docs/index.html)
-    /// and the implemented ones can be found in [`ThumbnailType`].
+    ///   and the implemented ones can be found in [`ThumbnailType`].
     ///
     /// # Errors
     /// * All errors under [Standard Errors](#standard-errors).
@@ -295,6 +297,8 @@ fn generate_request_id_string(
             format!("{}:undefined:AvatarHeadshot:{}:null:regular", id, size)
         }
         ThumbnailType::Asset => format!("{}::Asset:{}:png:regular", id, size),
+        ThumbnailType::Outfit => format!("{}::Outfit:{}:png:regular", id, size),
+        ThumbnailType::BadgeIcon => format!("{}::BadgeIcon:{}:png:regular", id, size),
     }
 }
 
@@ -303,6 +307,8 @@ fn generate_format(thumbnail_type: ThumbnailType) -> Option<String> {
         ThumbnailType::Avatar => None::<String>,
         ThumbnailType::AvatarHeadshot => None::<String>,
         ThumbnailType::Asset => Some("png".to_string()),
+        ThumbnailType::Outfit => Some("png".to_string()),
+        ThumbnailType::BadgeIcon => Some("png".to_string()),
     }
 }
 
@@ -311,5 +317,7 @@ fn generate_thumbnail_type_string(thumbnail_type: ThumbnailType) -> String {
         ThumbnailType::Avatar => "Avatar".to_string(),
         ThumbnailType::AvatarHeadshot => "AvatarHeadShot".to_string(),
         ThumbnailType::Asset => "Asset".to_string(),
+        ThumbnailType::Outfit => "Outfit".to_string(),
+        ThumbnailType::BadgeIcon => "BadgeIcon".to_string(),
     }
 }
@@ -1,6 +1,7 @@
-use crate::{Client, RoboatError, User};
+use crate::{Client, Limit, RoboatError, User};
 use reqwest::header::{self, HeaderValue};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 mod request_types;
 
@@ -8,6 +9,27 @@ const AUTHENTICATED_USER_DETAILS_API: &str = "https://users.roblox.com/v1/users/
 const USERS_SEARCH_API: &str = "https://users.roblox.com/v1/users/search";
 const USER_DETAILS_API: &str = "https://users.roblox.com/v1/users/{user_id}";
 const USER_FROM_USERNAME_API: &str = "https://users.roblox.com/v1/usernames/users";
+const USERS_FROM_IDS_API: &str = "https://users.roblox.com/v1/users";
+
+/// The maximum amount of users that can be requested at once from
+/// <https://users.roblox.com/v1/users> and <https://users.roblox.com/v1/usernames/users>.
+const BULK_USERS_CHUNK_SIZE: usize = 100;
+
+/// Username history is returned newest to oldest.
+const USERNAME_HISTORY_SORT_ORDER: &str = "Desc";
+const USERNAME_HISTORY_API: &str =
+    "https://users.roblox.com/v1/users/{user_id}/username-history?limit={limit}&cursor={cursor}&sortOrder={sort_order}";
+
+const VALIDATE_DISPLAY_NAME_API: &str =
+    "https://users.roblox.com/v1/users/{user_id}/display-names/validate";
+const SET_DISPLAY_NAME_API: &str = "https://users.roblox.com/v1/users/{user_id}/display-names";
+
+const AGE_BRACKET_API: &str = "https://users.roblox.com/v1/users/authenticated/age-bracket";
+const EMAIL_API: &str = "https://accountsettings.roblox.com/v1/email";
+const PHONE_API: &str = "https://accountinformation.roblox.com/v1/phone";
+const PREMIUM_MEMBERSHIP_API: &str =
+    "https://premiumfeatures.roblox.com/v1/users/{user_id}/validate-membership";
+const NOT_APPROVED_API: &str = "https://usermoderation.roblox.com/v1/not-approved";
 
 // TODO: try to make a unified user details struct
 
@@ -58,6 +80,100 @@ pub struct UsernameUserDetails {
     pub has_verified_badge: bool,
 }
 
+/// Custom Roblox errors that occur when using [`Client::user_search`] or [`Client::user_search_page`].
+#[non_exhaustive]
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum UserSearchError {
+    /// Thrown when Roblox filters the keyword. There is no point in retrying with the same keyword.
+    #[default]
+    #[error("Keyword Filtered")]
+    KeywordFiltered,
+    /// Thrown when the keyword is too short to search with.
+    #[error("Keyword Too Short")]
+    KeywordTooShort,
+}
+
+/// A user returned from a search. Retrieved using [`Client::user_search_page`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UserSearchResult {
+    /// The id of the user.
+    pub user_id: u64,
+    /// The current username of the user.
+    pub username: String,
+    /// The display name of the user.
+    pub display_name: String,
+    /// Whether the user has a verified badge.
+    pub has_verified_badge: bool,
+    /// Previous usernames of the user that matched the keyword.
+    pub previous_usernames: Vec<String>,
+}
+
+/// The age bracket of an account, as reported by Roblox.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum AgeBracket {
+    #[default]
+    ThirteenOrOver,
+    UnderThirteen,
+}
+
+impl TryFrom<u8> for AgeBracket {
+    type Error = RoboatError;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            0 => Ok(Self::ThirteenOrOver),
+            1 => Ok(Self::UnderThirteen),
+            _ => Err(RoboatError::MalformedResponse),
+        }
+    }
+}
+
+/// The status of the session belonging to the roblosecurity in the client.
+/// Retrieved using [`Client::session_status`].
+///
+/// If `is_valid` is false, every other field is left as its default value.
+///
+/// Every field other than `is_valid` and `user_id` comes from a separate endpoint. Some of
+/// these endpoints reject moderated or restricted accounts, so a field is `None` if its
+/// endpoint failed.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct SessionStatus {
+    /// Whether the roblosecurity is valid.
+    pub is_valid: bool,
+    /// The user id of the account.
+    pub user_id: u64,
+    /// Whether the account is terminated.
+    pub is_terminated: Option<bool>,
+    /// Whether the account is currently under moderation (warned or temporarily banned).
+    pub is_moderated: Option<bool>,
+    /// The description of the moderation action, such as "Ban 1 Day". Only exists if
+    /// the account is under moderation.
+    pub moderation_type: Option<String>,
+    /// Whether the account has a verified email.
+    pub is_email_verified: Option<bool>,
+    /// Whether the account has a verified phone number.
+    pub is_phone_verified: Option<bool>,
+    /// Whether the account has an active premium membership.
+    pub is_premium: Option<bool>,
+    /// The age bracket of the account.
+    pub age_bracket: Option<AgeBracket>,
+}
+
 impl Client {
     /// Grabs information about the user from <https://catalog.roblox.com/v1/catalog/items/details> using the
     /// Roblosecurity inside the client.
@@ -89,15 +205,17 @@ impl Client {
 
     /// Searches for a user using <https://users.roblox.com/v1/users/search>.
     ///
+    /// Only the first page of results is returned. To page through results, or to get
+    /// previous usernames and verified badges, use [`Client::user_search_page`].
+    ///
     /// # Notes
     /// * Does not require a valid roblosecurity.
     /// * HOWEVER, if a valid roblosecurity is not provided then there will be a very low rate limit.
-    /// * The cursors in this response are not used as using them is currently broken.
-    /// * Limits are not used for the same reason (the endpoint does not respect them).
     ///
     /// # Errors
     /// * All errors under [Standard Errors](#standard-errors).
     /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::UserSearchError`] - Nested inside this error, all variants of [`UserSearchError`] may be thrown.
     ///
     /// # Example
     ///
@@ -124,19 +242,9 @@ impl Client {
     /// # }
     /// ```
     pub async fn user_search(&self, keyword: String) -> Result<Vec<User>, RoboatError> {
-        let formatted_url = format!("{}?keyword={}", USERS_SEARCH_API, keyword);
+        let query = [("keyword", keyword)];
 
-        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
-
-        let request_result = self
-            .reqwest_client
-            .get(formatted_url)
-            .header(header::COOKIE, cookie_string)
-            .send()
-            .await;
-
-        let response = Self::validate_request_result(request_result).await?;
-        let raw = Self::parse_to_raw::<request_types::UserSearchResponse>(response).await?;
+        let raw = self.user_search_internal(&query).await?;
 
         let mut users = Vec::new();
 
@@ -153,6 +261,114 @@ impl Client {
         Ok(users)
     }
 
+    /// Fetches a page of user search results using <https://users.roblox.com/v1/users/search>.
+    ///
+    /// Unlike [`Client::user_search`], this returns previous usernames and verified badges, and
+    /// supports cursors.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * HOWEVER, if a valid roblosecurity is not provided then there will be a very low rate limit.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of search results.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::UserSearchError`] - Nested inside this error, all variants of [`UserSearchError`] may be thrown.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    /// const KEYWORD: &str = "linkmon";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (results, next_cursor) = client
+    ///     .user_search_page(KEYWORD.to_string(), Limit::Ten, None)
+    ///     .await?;
+    ///
+    /// for result in results {
+    ///     println!(
+    ///         "{}: {} / Verified: {} / Previous Usernames: {:?}",
+    ///         result.username, result.user_id, result.has_verified_badge, result.previous_usernames
+    ///     );
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_search_page(
+        &self,
+        keyword: String,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<UserSearchResult>, Option<String>), RoboatError> {
+        let query = [
+            ("keyword", keyword),
+            ("limit", limit.to_u64().to_string()),
+            ("cursor", cursor.unwrap_or_default()),
+        ];
+
+        let raw = self.user_search_internal(&query).await?;
+
+        let results = raw
+            .data
+            .into_iter()
+            .map(|user| UserSearchResult {
+                user_id: user.id,
+                username: user.name,
+                display_name: user.display_name,
+                has_verified_badge: user.has_verified_badge,
+                previous_usernames: user.previous_usernames,
+            })
+            .collect();
+
+        Ok((results, raw.next_page_cursor))
+    }
+
+    /// Sends a request to <https://users.roblox.com/v1/users/search> and converts
+    /// rejected keywords into a [`UserSearchError`].
+    ///
+    /// The query is passed separately so that the keyword and cursor are url encoded.
+    async fn user_search_internal(
+        &self,
+        query: &[(&str, String)],
+    ) -> Result<request_types::UserSearchResponse, RoboatError> {
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request_result = self
+            .reqwest_client
+            .get(USERS_SEARCH_API)
+            .query(query)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = match Self::validate_request_result(request_result).await {
+            Ok(x) => x,
+            Err(RoboatError::UnknownRobloxErrorCode { code, message }) => {
+                let error = match code {
+                    5 => UserSearchError::KeywordFiltered,
+                    6 => UserSearchError::KeywordTooShort,
+                    _ => return Err(RoboatError::UnknownRobloxErrorCode { code, message }),
+                };
+
+                return Err(RoboatError::UserSearchError(error));
+            }
+            Err(e) => return Err(e),
+        };
+
+        Self::parse_to_raw::<request_types::UserSearchResponse>(response).await
+    }
+
     /// Fetches user details using <https://users.roblox.com/v1/users/{user_id}>.
     ///
     /// For bulk fetching, it is recommended to use [`Client::username_user_details`]
@@ -208,8 +424,9 @@ impl Client {
     /// # Notes
     /// * Does not require a valid roblosecurity.
     /// * This is virtually the same as [`Client::user_details`] except that it can
-    /// fetch multiple users at once, and it searches using usernames instead of user IDs.
+    ///   fetch multiple users at once, and it searches using usernames instead of user IDs.
     /// * The usernames are not case sensitive.
+    /// * Usernames are requested in chunks of 100, so any amount of usernames can be passed.
     ///
     /// # Errors
     /// * All errors under [Standard Errors](#standard-errors).
@@ -241,31 +458,493 @@ impl Client {
         usernames: Vec<String>,
         exclude_banned_users: bool,
     ) -> Result<Vec<UsernameUserDetails>, RoboatError> {
-        let request_result = self
-            .reqwest_client
-            .post(USER_FROM_USERNAME_API)
-            .json(&request_types::UsernameUserDetailsRequest {
-                usernames,
-                exclude_banned_users,
-            })
-            .send()
-            .await;
+        let mut users = Vec::new();
 
-        let response = Self::validate_request_result(request_result).await?;
-        let raw =
-            Self::parse_to_raw::<request_types::UsernameUserDetailsResponse>(response).await?;
+        for chunk in usernames.chunks(BULK_USERS_CHUNK_SIZE) {
+            let request_result = self
+                .reqwest_client
+                .post(USER_FROM_USERNAME_API)
+                .json(&request_types::UsernameUserDetailsRequest {
+                    usernames: chunk.to_vec(),
+                    exclude_banned_users,
+                })
+                .send()
+                .await;
 
-        let users = raw
-            .data
-            .into_iter()
-            .map(|user| UsernameUserDetails {
+            let response = Self::validate_request_result(request_result).await?;
+            let raw =
+                Self::parse_to_raw::<request_types::UsernameUserDetailsResponse>(response).await?;
+
+            users.extend(raw.data.into_iter().map(|user| UsernameUserDetails {
                 requested_username: user.requested_username,
                 username: user.name,
                 display_name: user.display_name,
                 id: user.id,
                 has_verified_badge: user.has_verified_badge,
-            })
-            .collect();
+            }));
+        }
+
         Ok(users)
     }
+
+    /// Fetches multiple users by user id using <https://users.roblox.com/v1/users>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * User ids are requested in chunks of 100, so any amount of user ids can be passed.
+    /// * Chunks are requested one after another to avoid hitting the rate limit.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of users, in the same order as `user_ids`.
+    /// * The second value is a vector of the user ids that were not returned by Roblox,
+    ///   which happens when the user does not exist or has been deleted.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_ids = vec![2207291, 156, 1];
+    /// let (users, missing_user_ids) = client.user_details_bulk(user_ids).await?;
+    ///
+    /// for user in users {
+    ///     println!("{}: {}", user.user_id, user.username);
+    /// }
+    ///
+    /// println!("Missing user ids: {:?}", missing_user_ids);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_details_bulk(
+        &self,
+        user_ids: Vec<u64>,
+    ) -> Result<(Vec<User>, Vec<u64>), RoboatError> {
+        let mut found_users = HashMap::new();
+
+        for chunk in user_ids.chunks(BULK_USERS_CHUNK_SIZE) {
+            let request_result = self
+                .reqwest_client
+                .post(USERS_FROM_IDS_API)
+                .json(&request_types::UserDetailsBulkReqBody {
+                    user_ids: chunk.to_vec(),
+                    exclude_banned_users: false,
+                })
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw =
+                Self::parse_to_raw::<request_types::UserDetailsBulkResponse>(response).await?;
+
+            for user in raw.data {
+                found_users.insert(
+                    user.id,
+                    User {
+                        user_id: user.id,
+                        username: user.name,
+                        display_name: user.display_name,
+                    },
+                );
+            }
+        }
+
+        let mut users = Vec::new();
+        let mut missing_user_ids = Vec::new();
+
+        for user_id in user_ids {
+            match found_users.get(&user_id) {
+                Some(user) => users.push(user.clone()),
+                None => missing_user_ids.push(user_id),
+            }
+        }
+
+        Ok((users, missing_user_ids))
+    }
+
+    /// Fetches a page of the previous usernames of a user using
+    /// <https://users.roblox.com/v1/users/{user_id}/username-history>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Usernames are ordered newest to oldest, and do not include the current username.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of previous usernames.
+    /// * The second value is the cursor for the next page. It is `None` if there are no more pages.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const USER_ID: u64 = 2207291;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (usernames, next_cursor) = client
+    ///         .username_history(USER_ID, Limit::Hundred, cursor)
+    ///         .await?;
+    ///
+    ///     for username in usernames {
+    ///         println!("Previous Username: {}", username);
+    ///     }
+    ///
+    ///     cursor = next_cursor;
+    ///
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn username_history(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<String>, Option<String>), RoboatError> {
+        let formatted_url = USERNAME_HISTORY_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{limit}", &limit.to_u64().to_string())
+            .replace("{cursor}", &cursor.unwrap_or_default())
+            .replace("{sort_order}", USERNAME_HISTORY_SORT_ORDER);
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::UsernameHistoryResponse>(response).await?;
+
+        let usernames = raw.data.into_iter().map(|x| x.name).collect();
+
+        Ok((usernames, raw.next_page_cursor))
+    }
+
+    /// Checks whether the authenticated user can change their display name to `display_name` using
+    /// <https://users.roblox.com/v1/users/{user_id}/display-names/validate>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * Will return `Ok(())` if the display name is valid.
+    /// * If the display name is invalid (too long, moderated, on cooldown, etc.), the reason is
+    ///   returned as [`RoboatError::UnknownRobloxErrorCode`].
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// match client.validate_display_name("NewName".to_string()).await {
+    ///     Ok(_) => println!("Display name is valid."),
+    ///     Err(e) => println!("Display name is invalid: {}", e),
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_display_name(&self, display_name: String) -> Result<(), RoboatError> {
+        let cookie = self.cookie_string()?;
+        let user_id = self.user_id().await?;
+
+        let formatted_url = VALIDATE_DISPLAY_NAME_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .query(&[("displayName", &display_name)])
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let _ = Self::validate_request_result(request_result).await?;
+
+        // If we got a status code 200, the display name is valid.
+
+        Ok(())
+    }
+
+    /// Changes the display name of the authenticated user using
+    /// <https://users.roblox.com/v1/users/{user_id}/display-names>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Updates the cached display name (used by [`Client::display_name`]) on success.
+    ///
+    /// # Return Value Notes
+    /// * Will return `Ok(())` if the display name was successfully changed.
+    /// * If the display name is rejected, the reason is returned as [`RoboatError::UnknownRobloxErrorCode`].
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client.set_display_name("NewName".to_string()).await?;
+    ///
+    /// println!("Display name is now {}", client.display_name().await?);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_display_name(&self, display_name: String) -> Result<(), RoboatError> {
+        match self.set_display_name_internal(display_name.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_display_name_internal(display_name).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Fetches the status of the session belonging to the roblosecurity, assembled from
+    /// <https://users.roblox.com/v1/users/authenticated>, <https://users.roblox.com/v1/users/{user_id}>,
+    /// <https://users.roblox.com/v1/users/authenticated/age-bracket>, <https://usermoderation.roblox.com/v1/not-approved>,
+    /// <https://accountsettings.roblox.com/v1/email>, <https://accountinformation.roblox.com/v1/phone>,
+    /// and <https://premiumfeatures.roblox.com/v1/users/{user_id}/validate-membership>.
+    ///
+    /// # Notes
+    /// * Requires a roblosecurity to be set, but does not require it to be valid.
+    /// * Once the roblosecurity is known to be valid, the remaining endpoints are requested in parallel.
+    /// * Only a failure of <https://users.roblox.com/v1/users/authenticated> is returned as an error.
+    ///   If any other endpoint fails, its field is set to `None`.
+    /// * Caches the user id, username, and display name if the roblosecurity is valid.
+    ///
+    /// # Return Value Notes
+    /// * If the roblosecurity is invalid, `Ok` is returned with [`SessionStatus::is_valid`] set to false.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::RoblosecurityNotSet`]
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let status = client.session_status().await?;
+    ///
+    /// if !status.is_valid {
+    ///     println!("Roblosecurity is invalid.");
+    /// } else {
+    ///     println!("User ID: {}", status.user_id);
+    ///     println!("Moderated: {:?}", status.is_moderated);
+    ///     println!("Email Verified: {:?}", status.is_email_verified);
+    ///     println!("Premium: {:?}", status.is_premium);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn session_status(&self) -> Result<SessionStatus, RoboatError> {
+        let user_information = match self.user_information_internal().await {
+            Ok(x) => x,
+            Err(RoboatError::InvalidRoblosecurity) => return Ok(SessionStatus::default()),
+            Err(e) => return Err(e),
+        };
+
+        let user_id = user_information.user_id;
+
+        // Each endpoint is allowed to fail on its own, as some of them reject
+        // moderated or restricted accounts.
+        let (user_details, age_bracket, moderation, email, phone, is_premium) = tokio::join!(
+            self.user_details(user_id),
+            self.age_bracket_internal(),
+            self.not_approved_internal(),
+            self.email_internal(),
+            self.phone_internal(),
+            self.premium_membership_internal(user_id),
+        );
+
+        let moderation_type = moderation
+            .as_ref()
+            .ok()
+            .and_then(|moderation| moderation.punishment_type_description.clone());
+
+        Ok(SessionStatus {
+            is_valid: true,
+            user_id,
+            is_terminated: user_details.ok().map(|details| details.is_terminated),
+            is_moderated: moderation
+                .ok()
+                .map(|moderation| moderation.punishment_type_description.is_some()),
+            moderation_type,
+            is_email_verified: email.ok().map(|email| email.verified),
+            is_phone_verified: phone.ok().map(|phone| phone.is_verified),
+            is_premium: is_premium.ok(),
+            age_bracket: age_bracket.ok(),
+        })
+    }
+}
+
+mod internal {
+    use super::{
+        request_types, AgeBracket, AGE_BRACKET_API, EMAIL_API, NOT_APPROVED_API, PHONE_API,
+        PREMIUM_MEMBERSHIP_API, SET_DISPLAY_NAME_API,
+    };
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn set_display_name_internal(
+            &self,
+            display_name: String,
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+            let user_id = self.user_id().await?;
+
+            let formatted_url = SET_DISPLAY_NAME_API.replace("{user_id}", &user_id.to_string());
+
+            let json = serde_json::json!({ "newDisplayName": display_name });
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .json(&json)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+            self.set_cached_display_name(display_name).await;
+
+            Ok(())
+        }
+
+        pub(super) async fn age_bracket_internal(&self) -> Result<AgeBracket, RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .get(AGE_BRACKET_API)
+                .header(header::COOKIE, cookie)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::AgeBracketResponse>(response).await?;
+
+            AgeBracket::try_from(raw.age_bracket)
+        }
+
+        pub(super) async fn not_approved_internal(
+            &self,
+        ) -> Result<request_types::NotApprovedResponse, RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .get(NOT_APPROVED_API)
+                .header(header::COOKIE, cookie)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            Self::parse_to_raw::<request_types::NotApprovedResponse>(response).await
+        }
+
+        pub(super) async fn email_internal(
+            &self,
+        ) -> Result<request_types::EmailResponse, RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .get(EMAIL_API)
+                .header(header::COOKIE, cookie)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            Self::parse_to_raw::<request_types::EmailResponse>(response).await
+        }
+
+        pub(super) async fn phone_internal(
+            &self,
+        ) -> Result<request_types::PhoneResponse, RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .get(PHONE_API)
+                .header(header::COOKIE, cookie)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            Self::parse_to_raw::<request_types::PhoneResponse>(response).await
+        }
+
+        pub(super) async fn premium_membership_internal(
+            &self,
+            user_id: u64,
+        ) -> Result<bool, RoboatError> {
+            let formatted_url = PREMIUM_MEMBERSHIP_API.replace("{user_id}", &user_id.to_string());
+
+            let cookie = self.cookie_string()?;
+
+            let request_result = self
+                .reqwest_client
+                .get(formatted_url)
+                .header(header::COOKIE, cookie)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            Self::parse_to_raw::<bool>(response).await
+        }
+    }
 }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub(super) struct UserSearchResponse {
     pub previous_page_cursor: Option<String>,
-    pub next_page_cursor: String,
+    pub next_page_cursor: Option<String>,
     pub data: Vec<UserSearchUserInformationRaw>,
 }
 
@@ -38,3 +38,65 @@ pub(super) struct UsernameUserInformationRaw {
 pub(super) struct UsernameUserDetailsResponse {
     pub data: Vec<UsernameUserInformationRaw>,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserDetailsBulkReqBody {
+    pub user_ids: Vec<u64>,
+    pub exclude_banned_users: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserDetailsBulkResponse {
+    pub data: Vec<UserDetailsBulkRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserDetailsBulkRaw {
+    pub has_verified_badge: bool,
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UsernameHistoryResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<UsernameHistoryRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct UsernameHistoryRaw {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AgeBracketResponse {
+    pub age_bracket: u8,
+}
+
+/// Is an empty object if the account is not moderated.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct NotApprovedResponse {
+    pub punishment_type_description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct EmailResponse {
+    pub email_address: Option<String>,
+    pub verified: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PhoneResponse {
+    pub phone: Option<String>,
+    pub is_verified: bool,
+}